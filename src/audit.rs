@@ -0,0 +1,251 @@
+// src/audit.rs
+// Fusion des connaissances à la station avec détection des désaccords: deux observations d'une
+// même case qui en donnent une nature différente. Chaque désaccord est tranché par la règle de
+// résolution choisie (interchangeable depuis la Terre ou le scénario) et consigné dans un journal
+// d'audit: case, les deux versions, robots concernés et règle appliquée. Deux observations qui
+// concordent ne sont pas un conflit: la plus forte selon la règle rafraîchit simplement la case.
+// La règle ordonne aussi les registres des connaissances répliquées entre stations (voir crdt).
+use crate::types::{RobotType, TileType};
+use crate::station::TerrainData;
+use crate::knowledge::merge_weight;
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+// Nombre de conflits conservés par le journal d'audit
+pub const AUDIT_LOG_CAPACITY: usize = 500;
+// Nombre de conflits retournés par défaut par une requête
+pub const DEFAULT_QUERY_LIMIT: usize = 10;
+
+// Règle de résolution des désaccords entre deux observations d'une même case
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResolutionPolicy {
+    #[default]
+    Weighted,                   // Confiance plus bonus de fraîcheur (règle des autres fusions)
+    LatestWins,                 // La plus récente; à égalité, la plus sûre
+    MostConfident,              // La plus sûre; à égalité, la plus récente
+    TrustedType(RobotType),     // Celle du type de robot de confiance; sinon la plus lourde
+}
+
+impl ResolutionPolicy {
+    // Variantes proposées à la Terre (le type de confiance est l'explorateur)
+    pub const ALL: [ResolutionPolicy; 4] = [
+        ResolutionPolicy::Weighted,
+        ResolutionPolicy::LatestWins,
+        ResolutionPolicy::MostConfident,
+        ResolutionPolicy::TrustedType(RobotType::Explorer),
+    ];
+
+    // Rang de l'observation entrante face à l'observation connue. À égalité selon la règle, la plus
+    // récente puis celle du robot au plus grand identifiant l'emporte: l'ordre est total, si bien
+    // que toutes les stations tranchent de la même façon quel que soit l'ordre des fusions
+    pub fn compare(&self, known: &TerrainData, incoming: &TerrainData) -> Ordering {
+        let weight = || merge_weight(incoming).total_cmp(&merge_weight(known));
+        let latest = || incoming.timestamp.cmp(&known.timestamp);
+        let confidence = || incoming.confidence.total_cmp(&known.confidence);
+        let ranked = match self {
+            ResolutionPolicy::Weighted => weight(),
+            ResolutionPolicy::LatestWins => latest().then_with(confidence),
+            ResolutionPolicy::MostConfident => confidence().then_with(latest),
+            ResolutionPolicy::TrustedType(trusted) => {
+                (incoming.robot_type == *trusted).cmp(&(known.robot_type == *trusted)).then_with(weight)
+            },
+        };
+        ranked.then_with(latest).then_with(|| incoming.robot_id.cmp(&known.robot_id))
+    }
+
+    // L'observation entrante remplace-t-elle l'observation connue ?
+    pub fn prefers(&self, known: &TerrainData, incoming: &TerrainData) -> bool {
+        self.compare(known, incoming) == Ordering::Greater
+    }
+}
+
+impl fmt::Display for ResolutionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolutionPolicy::Weighted => write!(f, "confiance et fraîcheur"),
+            ResolutionPolicy::LatestWins => write!(f, "la plus récente"),
+            ResolutionPolicy::MostConfident => write!(f, "la plus sûre"),
+            ResolutionPolicy::TrustedType(robot_type) => write!(f, "confiance aux {:?}", robot_type),
+        }
+    }
+}
+
+// Version d'une case telle qu'un robot ou la station la rapporte
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    pub tile: TileType,
+    pub robot_id: usize,
+    pub robot_type: RobotType,
+    pub timestamp: u32,
+    pub confidence: f32,
+}
+
+impl From<&TerrainData> for Observation {
+    fn from(data: &TerrainData) -> Self {
+        Self {
+            tile: data.tile.clone(),
+            robot_id: data.robot_id,
+            robot_type: data.robot_type,
+            timestamp: data.timestamp,
+            confidence: data.confidence,
+        }
+    }
+}
+
+impl fmt::Display for Observation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} (robot #{}, t={}, {:.0}%)", self.tile, self.robot_id, self.timestamp, self.confidence * 100.0)
+    }
+}
+
+// Désaccord consigné dans le journal d'audit
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub time: u32,                  // Heure de la station au moment de la fusion
+    pub position: (usize, usize),
+    pub known: Observation,         // Version connue de la station
+    pub incoming: Observation,      // Version apportée par le robot
+    pub policy: ResolutionPolicy,   // Règle appliquée
+    pub incoming_won: bool,         // La version du robot a remplacé celle de la station
+}
+
+impl ConflictRecord {
+    pub fn winner(&self) -> &Observation {
+        if self.incoming_won { &self.incoming } else { &self.known }
+    }
+
+    pub fn involves(&self, robot_id: usize) -> bool {
+        self.known.robot_id == robot_id || self.incoming.robot_id == robot_id
+    }
+}
+
+impl fmt::Display for ConflictRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "t={} ({},{}): {} contre {} -> {:?} ({})", self.time, self.position.0, self.position.1,
+               self.known, self.incoming, self.winner().tile, self.policy)
+    }
+}
+
+// Requête sur le journal d'audit (critères absents: pas de filtre)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConflictQuery {
+    pub position: Option<(usize, usize)>,
+    pub robot_id: Option<usize>,
+    pub since: Option<u32>,
+    pub limit: usize,               // Nombre maximal de conflits, les plus récents
+}
+
+impl Default for ConflictQuery {
+    fn default() -> Self {
+        Self { position: None, robot_id: None, since: None, limit: DEFAULT_QUERY_LIMIT }
+    }
+}
+
+impl ConflictQuery {
+    pub fn matches(&self, record: &ConflictRecord) -> bool {
+        self.position.is_none_or(|position| record.position == position)
+            && self.robot_id.is_none_or(|robot_id| record.involves(robot_id))
+            && self.since.is_none_or(|since| record.time >= since)
+    }
+}
+
+impl fmt::Display for ConflictQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut criteria = Vec::new();
+        if let Some((x, y)) = self.position {
+            criteria.push(format!("case ({},{})", x, y));
+        }
+        if let Some(robot_id) = self.robot_id {
+            criteria.push(format!("robot #{}", robot_id));
+        }
+        if let Some(since) = self.since {
+            criteria.push(format!("depuis t={}", since));
+        }
+        if criteria.is_empty() {
+            write!(f, "tous")
+        } else {
+            write!(f, "{}", criteria.join(", "))
+        }
+    }
+}
+
+// Journal circulaire des derniers conflits résolus
+#[derive(Clone, Debug, Default)]
+pub struct AuditLog {
+    records: VecDeque<ConflictRecord>,
+    total: usize,                   // Conflits consignés depuis le début de la mission
+    // Observations déjà écartées, par case: un robot qui rapporte encore une version écartée
+    // (par radio, à chaque tour) ne crée pas un nouveau conflit
+    rejected: HashSet<((usize, usize), usize, u32)>,
+}
+
+impl AuditLog {
+    // Consigne un conflit; retourne false si l'observation écartée l'a déjà été sur cette case
+    pub fn push(&mut self, record: ConflictRecord) -> bool {
+        let loser = if record.incoming_won { &record.known } else { &record.incoming };
+        if !self.rejected.insert((record.position, loser.robot_id, loser.timestamp)) {
+            return false;
+        }
+
+        if self.records.len() == AUDIT_LOG_CAPACITY {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+        self.total += 1;
+        true
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    // Conflits conservés qui répondent à la requête, du plus ancien au plus récent
+    pub fn query(&self, query: &ConflictQuery) -> Vec<ConflictRecord> {
+        let mut matching: Vec<ConflictRecord> = self.records.iter().rev()
+            .filter(|record| query.matches(record))
+            .take(query.limit)
+            .cloned()
+            .collect();
+        matching.reverse();
+        matching
+    }
+}
+
+// Fusionne une observation dans une case connue de la station, selon la règle (voir
+// `ResolutionPolicy::compare`). Un désaccord sur la nature de la case est retourné pour le
+// journal; deux observations concordantes ne sont pas un conflit, la plus forte rafraîchit
+// simplement la case. Retourne (case modifiée, conflit éventuel).
+pub fn merge_audited(known: &mut TerrainData, incoming: &TerrainData, policy: ResolutionPolicy,
+                     position: (usize, usize), now: u32) -> (bool, Option<ConflictRecord>) {
+    if !incoming.explored {
+        return (false, None);
+    }
+    if !known.explored {
+        *known = incoming.clone();
+        return (true, None);
+    }
+
+    let incoming_won = policy.prefers(known, incoming);
+    let record = (known.tile != incoming.tile).then(|| ConflictRecord {
+        time: now,
+        position,
+        known: Observation::from(&*known),
+        incoming: Observation::from(incoming),
+        policy,
+        incoming_won,
+    });
+    if incoming_won {
+        *known = incoming.clone();
+    }
+    (incoming_won, record)
+}
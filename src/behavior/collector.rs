@@ -0,0 +1,58 @@
+// src/behavior/collector.rs
+use crate::movement::STEP_COST;
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::Station;
+use super::{Action, Behavior, Observation};
+
+// Distance de détection d'une ressource pendant l'exploration (en cases)
+const DETECTION_RANGE: usize = 5;
+
+// Les collecteurs exécutent les contrats de collecte attribués par la station et rapportent
+// la ressource; sans contrat, ils explorent et ne ramassent que les gisements tout proches
+#[derive(Default)]
+pub struct CollectorBehavior {
+    contested: bool,   // Le gisement le plus proche est sous contrat avec un autre robot
+}
+
+impl Behavior for CollectorBehavior {
+    fn name(&self) -> &str {
+        "collector"
+    }
+
+    fn observe(&mut self, robot: &Robot, map: &Map, station: &Station) -> Observation {
+        let observation = Observation::gather(robot, map);
+        self.contested = observation.nearest_resource.is_some_and(|(target, _)| {
+            station.market.tasks().iter()
+                .any(|t| t.position == target && t.assignee.is_some_and(|id| id != robot.id))
+        });
+        observation
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        if observation.must_head_home() {
+            return Action::ReturnToStation;
+        }
+
+        // Sur une ressource de son type: la collecter
+        if observation.target_resource.as_ref() == Some(&observation.tile) {
+            return Action::Collect;
+        }
+
+        // Contrat remporté aux enchères: aller au gisement
+        if let Some(task) = observation.task {
+            return Action::MoveTo(task);
+        }
+
+        match observation.nearest_resource {
+            // Aucune ressource accessible: rentrer, puis attendre à la station
+            None if observation.at_station => Action::Idle,
+            None => Action::ReturnToStation,
+
+            // Sans contrat, ne se détourner que pour une ressource proche et libre
+            Some((_, cost)) if cost > DETECTION_RANGE * STEP_COST || self.contested => Action::Explore,
+
+            Some((target, _)) => Action::MoveTo(target),
+        }
+    }
+}
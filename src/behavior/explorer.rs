@@ -0,0 +1,19 @@
+// src/behavior/explorer.rs
+use super::{Action, Behavior, Observation};
+
+// L'explorateur cartographie le terrain par frontières et ne rentre que pour recharger
+pub struct ExplorerBehavior;
+
+impl Behavior for ExplorerBehavior {
+    fn name(&self) -> &str {
+        "explorer"
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        if observation.must_head_home() {
+            Action::ReturnToStation
+        } else {
+            Action::Explore
+        }
+    }
+}
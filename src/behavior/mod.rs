@@ -0,0 +1,144 @@
+// src/behavior/mod.rs
+// Comportements des robots: chaque type de robot est piloté par une implémentation
+// du trait `Behavior` (observer → décider → agir), enregistrée dans un `BehaviorRegistry`.
+mod explorer;
+mod collector;
+mod relay;
+mod repair;
+
+pub use explorer::ExplorerBehavior;
+pub use collector::CollectorBehavior;
+pub use relay::RelayBehavior;
+pub use repair::RepairBehavior;
+
+use crate::types::{TileType, RobotType, RobotMode};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::Station;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// Ce que le robot perçoit au début de son tour
+#[derive(Clone, Debug)]
+pub struct Observation {
+    pub position: (usize, usize),
+    pub home: (usize, usize),
+    pub at_station: bool,
+    pub in_contact: bool,                                 // À portée radio de la station
+    pub mode: RobotMode,
+    pub energy: f32,
+    pub max_energy: f32,
+    pub tile: TileType,                                   // Tuile sous le robot
+    pub needs_return: bool,                               // Énergie faible ou inventaire plein
+    pub target_resource: Option<TileType>,                // Ressource collectée par ce type de robot
+    pub nearest_resource: Option<((usize, usize), usize)>, // Position et coût de trajet
+    pub task: Option<(usize, usize)>,                     // Gisement attribué par la station
+}
+
+impl Observation {
+    // Observation standard construite à partir de l'état du robot et de la carte
+    pub fn gather(robot: &Robot, map: &Map) -> Self {
+        let position = (robot.x, robot.y);
+        let target_resource = robot.target_resource();
+
+        Self {
+            position,
+            home: (robot.home_station_x, robot.home_station_y),
+            at_station: robot.is_at_station(),
+            in_contact: robot.in_contact,
+            mode: robot.mode,
+            energy: robot.energy,
+            max_energy: robot.max_energy,
+            tile: map.get_tile(robot.x, robot.y),
+            needs_return: robot.should_return_to_station(map),
+            nearest_resource: target_resource.clone()
+                .and_then(|res| map.nearest_resource(position, res)),
+            target_resource,
+            task: robot.task,
+        }
+    }
+
+    // Retour en cours (rappel, recharge, inventaire plein) ou devenu nécessaire: tant que le
+    // robot n'est pas à la station, il y retourne
+    pub fn must_head_home(&self) -> bool {
+        !self.at_station && (self.mode == RobotMode::ReturnToStation || self.needs_return)
+    }
+}
+
+// Action décidée par un comportement pour le tour en cours
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Idle,                     // Rester sur place
+    Explore,                  // Avancer vers la meilleure frontière
+    MoveTo((usize, usize)),   // Avancer d'un pas vers une case
+    Collect,                  // Collecter la ressource sous le robot
+    ReturnToStation,          // Avancer d'un pas vers la station
+}
+
+// Stratégie de contrôle d'un robot
+pub trait Behavior: Send {
+    // Nom de la stratégie (affichage, journaux)
+    fn name(&self) -> &str;
+
+    // Percevoir l'environnement
+    fn observe(&mut self, robot: &Robot, map: &Map, _station: &Station) -> Observation {
+        Observation::gather(robot, map)
+    }
+
+    // Choisir une action à partir de l'observation
+    fn decide(&mut self, observation: &Observation) -> Action;
+
+    // Exécuter l'action choisie
+    fn act(&mut self, robot: &mut Robot, action: Action, map: &mut Map, station: &mut Station) {
+        robot.perform(action, map, station);
+    }
+}
+
+// Fabrique de comportement pour un type de robot
+pub type BehaviorFactory = Arc<dyn Fn() -> Box<dyn Behavior> + Send + Sync>;
+
+// Registre associant à chaque type de robot la fabrique de son comportement
+#[derive(Clone)]
+pub struct BehaviorRegistry {
+    factories: HashMap<RobotType, BehaviorFactory>,
+}
+
+impl Default for BehaviorRegistry {
+    // Comportements fournis par la bibliothèque
+    fn default() -> Self {
+        let mut registry = Self { factories: HashMap::new() };
+        for robot_type in [RobotType::Explorer, RobotType::EnergyCollector, RobotType::MineralCollector,
+                           RobotType::ScientificCollector, RobotType::Relay, RobotType::Repair] {
+            registry.register(robot_type, move || default_behavior(robot_type));
+        }
+        registry
+    }
+}
+
+impl BehaviorRegistry {
+    // Enregistre (ou remplace) le comportement d'un type de robot
+    pub fn register<F>(&mut self, robot_type: RobotType, factory: F)
+    where
+        F: Fn() -> Box<dyn Behavior> + Send + Sync + 'static,
+    {
+        self.factories.insert(robot_type, Arc::new(factory));
+    }
+
+    // Crée le comportement d'un type de robot (tous les types ont un comportement enregistré
+    // dès la création du registre)
+    pub fn create(&self, robot_type: RobotType) -> Box<dyn Behavior> {
+        (self.factories[&robot_type])()
+    }
+}
+
+// Comportement par défaut d'un type de robot, tel que fourni par la bibliothèque
+pub fn default_behavior(robot_type: RobotType) -> Box<dyn Behavior> {
+    match robot_type {
+        RobotType::Explorer => Box::new(ExplorerBehavior),
+        RobotType::EnergyCollector | RobotType::MineralCollector | RobotType::ScientificCollector => {
+            Box::new(CollectorBehavior::default())
+        },
+        RobotType::Relay => Box::new(RelayBehavior::default()),
+        RobotType::Repair => Box::new(RepairBehavior::default()),
+    }
+}
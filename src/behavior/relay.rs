@@ -0,0 +1,36 @@
+// src/behavior/relay.rs
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::Station;
+use super::{Action, Behavior, Observation};
+
+// Le relais se poste en bordure de la couverture radio, en direction du robot
+// injoignable le plus éloigné, pour le reconnecter à la station
+#[derive(Default)]
+pub struct RelayBehavior {
+    post: Option<(usize, usize)>,
+}
+
+impl Behavior for RelayBehavior {
+    fn name(&self) -> &str {
+        "relay"
+    }
+
+    fn observe(&mut self, robot: &Robot, map: &Map, station: &Station) -> Observation {
+        self.post = station.comms.relay_post(robot.id, (robot.x, robot.y), map);
+        Observation::gather(robot, map)
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        if observation.must_head_home() {
+            return Action::ReturnToStation;
+        }
+
+        match self.post {
+            Some(post) if post == observation.position => Action::Idle,
+            Some(post) => Action::MoveTo(post),
+            None if observation.at_station => Action::Idle,
+            None => Action::ReturnToStation,
+        }
+    }
+}
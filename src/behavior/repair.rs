@@ -0,0 +1,38 @@
+// src/behavior/repair.rs
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::Station;
+use super::{Action, Behavior, Observation};
+
+// Le robot de réparation rejoint le robot usé que la station lui a affecté et reste à son
+// contact le temps de la réparation; sans affectation, il attend à la station
+#[derive(Default)]
+pub struct RepairBehavior {
+    patient: Option<(usize, usize)>,
+    adjacent: bool,
+}
+
+impl Behavior for RepairBehavior {
+    fn name(&self) -> &str {
+        "repair"
+    }
+
+    fn observe(&mut self, robot: &Robot, map: &Map, station: &Station) -> Observation {
+        self.patient = station.maintenance.assignment_of(robot.id);
+        self.adjacent = self.patient.is_some_and(|p| robot.is_next_to(map, p));
+        Observation::gather(robot, map)
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        if observation.must_head_home() {
+            return Action::ReturnToStation;
+        }
+
+        match self.patient {
+            Some(_) if self.adjacent => Action::Idle,
+            Some(patient) => Action::MoveTo(patient),
+            None if observation.at_station => Action::Idle,
+            None => Action::ReturnToStation,
+        }
+    }
+}
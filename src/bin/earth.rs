@@ -1,16 +1,25 @@
 // src/bin/earth.rs
 use ereea::types::{TileType, MAP_SIZE, RobotType, RobotMode};
-use ereea::network::{SimulationState, DEFAULT_PORT};
+use ereea::network::{SimulationState, EarthCommand, DEFAULT_PORT};
+use ereea::research::Technology;
+use ereea::construction::queue_summary;
+use ereea::audit::{ConflictQuery, ResolutionPolicy};
 
 use std::io::{stdout, Write};
+use std::sync::{Arc, Mutex};
 use crossterm::{
     ExecutableCommand,
     terminal::{enable_raw_mode, disable_raw_mode, Clear, ClearType},
     cursor::MoveTo,
     style::{Color, SetForegroundColor},
+    event::{self, Event, KeyCode, KeyModifiers},
 };
 use tokio::net::TcpStream;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+
+// Nombre de conflits du journal d'audit affichés
+const CONFLICTS_SHOWN: usize = 3;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -33,7 +42,44 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     println!("Connexion établie! Réception des données de la planète...");
     
-    let mut reader = BufReader::new(stream);
+    let (read_half, mut write_half) = stream.into_split();
+    
+    // Dernière commande de la file de construction et dernière case disputée, pour le clavier
+    let context = Arc::new(Mutex::new(KeyContext::default()));
+    
+    // Lecture du clavier dans un thread dédié: les commandes sont envoyées à la simulation
+    let (command_tx, mut command_rx) = mpsc::unbounded_channel::<EarthCommand>();
+    let context_for_keys = context.clone();
+    std::thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            let Event::Key(key) = event else { continue };
+            
+            // Le mode brut du terminal intercepte Ctrl+C
+            if key.code == KeyCode::Char('q')
+                || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)) {
+                let _ = disable_raw_mode();
+                std::process::exit(0);
+            }
+            
+            let current = *context_for_keys.lock().unwrap();
+            if let Some(command) = key_command(key.code, current) {
+                if command_tx.send(command).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    
+    tokio::spawn(async move {
+        while let Some(command) = command_rx.recv().await {
+            let Ok(json) = serde_json::to_string(&command) else { continue };
+            if write_half.write_all(format!("{}\n", json).as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+    
+    let mut reader = BufReader::new(read_half);
     let mut line = String::new();
     
     loop {
@@ -59,6 +105,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         };
         
+        *context.lock().unwrap() = KeyContext {
+            last_order: state.station_data.build_queue.last().map(|o| o.id),
+            last_conflict: state.station_data.conflicts.last().map(|c| c.position),
+        };
+        
         // Afficher l'état
         render_earth_interface(&state)?;
     }
@@ -68,6 +119,44 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+// État de la simulation utile aux touches de commande
+#[derive(Clone, Copy, Default)]
+struct KeyContext {
+    last_order: Option<usize>,              // Dernière commande de la file de construction
+    last_conflict: Option<(usize, usize)>,  // Case du dernier conflit transmis
+}
+
+// Touches de commande: initiale du robot à construire (comme sur la carte), x pour annuler la
+// dernière commande de la file, 1 à 4 pour choisir la recherche en cours, 5 à 8 pour la règle de
+// résolution des désaccords, c pour l'historique de la dernière case disputée et a pour revenir
+// aux derniers conflits
+fn key_command(code: KeyCode, context: KeyContext) -> Option<EarthCommand> {
+    if let KeyCode::Char(digit @ '1'..='4') = code {
+        let index = digit as usize - '1' as usize;
+        return Some(EarthCommand::Research(Technology::ALL[index]));
+    }
+    if let KeyCode::Char(digit @ '5'..='8') = code {
+        let index = digit as usize - '5' as usize;
+        return Some(EarthCommand::MergePolicy(ResolutionPolicy::ALL[index]));
+    }
+    
+    let robot_type = match code {
+        KeyCode::Char('e') => RobotType::Explorer,
+        KeyCode::Char('p') => RobotType::EnergyCollector,
+        KeyCode::Char('m') => RobotType::MineralCollector,
+        KeyCode::Char('s') => RobotType::ScientificCollector,
+        KeyCode::Char('r') => RobotType::Relay,
+        KeyCode::Char('t') => RobotType::Repair,
+        KeyCode::Char('x') => return context.last_order.map(EarthCommand::CancelBuild),
+        KeyCode::Char('c') => return context.last_conflict.map(|position| {
+            EarthCommand::QueryConflicts(ConflictQuery { position: Some(position), ..ConflictQuery::default() })
+        }),
+        KeyCode::Char('a') => return Some(EarthCommand::QueryConflicts(ConflictQuery::default())),
+        _ => return None,
+    };
+    Some(EarthCommand::Build(robot_type))
+}
+
 // Fonction pour afficher l'interface Terre
 fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::error::Error>> {
     let mut stdout = stdout();
@@ -98,6 +187,9 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
             if x == state.map_data.station_x && y == state.map_data.station_y {
                 stdout.execute(SetForegroundColor(Color::Yellow))?;
                 print!("[]");
+            } else if state.station_data.outposts.iter().any(|o| o.x == x && o.y == y) {
+                stdout.execute(SetForegroundColor(Color::DarkYellow))?;
+                print!("[]");
             } else if let Some(robot) = robot_here {
                 // Afficher le robot avec sa couleur
                 let color = match robot.robot_type {
@@ -105,6 +197,8 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
                     RobotType::EnergyCollector => Color::AnsiValue(10),
                     RobotType::MineralCollector => Color::AnsiValue(13),
                     RobotType::ScientificCollector => Color::AnsiValue(12),
+                    RobotType::Relay => Color::AnsiValue(14),
+                    RobotType::Repair => Color::AnsiValue(208),
                 };
                 
                 stdout.execute(SetForegroundColor(color))?;
@@ -114,6 +208,8 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
                     RobotType::EnergyCollector => "P",
                     RobotType::MineralCollector => "M",
                     RobotType::ScientificCollector => "S",
+                    RobotType::Relay => "R",
+                    RobotType::Repair => "T",
                 };
                 
                 print!("{}{}", display_char, robot.id);
@@ -159,24 +255,77 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
     stdout.execute(SetForegroundColor(Color::Yellow))?;
     println!("\n== RAPPORT DE LA STATION ==");
     stdout.execute(SetForegroundColor(Color::White))?;
-    println!("Énergie: {} | Minerais: {} | Données scientifiques: {} | Conflits de données: {}", 
+    println!("Énergie: {} | Minerais: {} | Données scientifiques: {} | Conflits de données: {} | Tâches: {} ouvertes, {} attribuées, {} réattribuées", 
              state.station_data.energy_reserves,
              state.station_data.collected_minerals,
              state.station_data.collected_scientific_data,
-             state.station_data.conflict_count);
-    println!("Statut: {}", state.station_data.status_message);
+             state.station_data.conflict_count,
+             state.station_data.open_tasks,
+             state.station_data.market_stats.awarded,
+             state.station_data.market_stats.reassigned);
+    println!("Statut: {} | Circulation: {} attentes, {} détours, {} interblocages résolus",
+             state.station_data.status_message,
+             state.station_data.traffic_stats.blocked_moves,
+             state.station_data.traffic_stats.detours,
+             state.station_data.traffic_stats.deadlocks_resolved);
+    println!("Quais: {} | {} tours d'attente (file la plus longue: {}) | {} énergie délivrée aux robots",
+             state.station_data.docking,
+             state.station_data.docking_stats.queue_waits,
+             state.station_data.docking_stats.longest_queue,
+             state.station_data.docking_stats.energy_delivered);
+    println!("Connaissances propagées: {} cases via la station | {} cases par radio | {} cases entre robots ({} rencontres) | {} répliquées entre stations",
+             state.station_data.knowledge_stats.tiles_via_station,
+             state.station_data.knowledge_stats.tiles_via_radio,
+             state.station_data.knowledge_stats.tiles_via_peers,
+             state.station_data.knowledge_stats.peer_encounters,
+             state.station_data.knowledge_stats.tiles_via_replication);
+    println!("Pannes: {} | {} robots relancés par transfert d'énergie | {} remorqués | {} abandonnés ({} unités de chargement perdues) | Recharges sur le terrain: {} ({:.0} énergie cédée)",
+             state.station_data.rescue_stats.stranded,
+             state.station_data.rescue_stats.recharged,
+             state.station_data.rescue_stats.towed,
+             state.station_data.rescue_stats.written_off,
+             state.station_data.rescue_stats.cargo_lost,
+             state.station_data.rescue_stats.field_charges,
+             state.station_data.rescue_stats.energy_delivered);
+    println!("Usure: {} défaillances | {} robots réparés sur le terrain | {} révisés à quai | {} batteries remplacées",
+             state.station_data.wear_stats.failures,
+             state.station_data.wear_stats.field_repairs,
+             state.station_data.wear_stats.station_repairs,
+             state.station_data.wear_stats.batteries_replaced);
+    println!("Construction: {}", queue_summary(&state.station_data.build_queue));
+    if let Some(plan) = &state.station_data.build_plan {
+        println!("Planification: {}", plan);
+    }
+    println!("Recherche: {}", state.station_data.research.summary());
+    let outposts = state.station_data.outposts.iter()
+        .map(|o| format!("#{} ({},{}) énergie {}, minerai {}, données {}, carte {:.0}%",
+                         o.id, o.x, o.y, o.energy_reserves, o.collected_minerals,
+                         o.collected_scientific_data, o.exploration_percentage))
+        .collect::<Vec<_>>();
+    println!("Avant-postes: {}", if outposts.is_empty() { "aucun".to_string() } else { outposts.join(" | ") });
+    
+    // Journal d'audit des désaccords entre observations: les derniers conflits de la requête
+    println!("Conflits de données: {} (règle: {}) | Requête: {}",
+             state.station_data.conflict_count,
+             state.station_data.merge_policy,
+             state.station_data.conflict_query);
+    for conflict in state.station_data.conflicts.iter().rev().take(CONFLICTS_SHOWN) {
+        println!("  {}", conflict);
+    }
     
     // Afficher les informations des robots
-    stdout.execute(MoveTo(0, station_y + 5))?;
+    stdout.execute(MoveTo(0, station_y + 13 + CONFLICTS_SHOWN as u16))?;
     stdout.execute(SetForegroundColor(Color::Cyan))?;
     println!("\n== STATUT DES ROBOTS ==");
     
-    for (i, robot) in state.robots_data.iter().enumerate() {
+    for robot in state.robots_data.iter() {
         let robot_color = match robot.robot_type {
             RobotType::Explorer => Color::AnsiValue(9),
             RobotType::EnergyCollector => Color::AnsiValue(10),
             RobotType::MineralCollector => Color::AnsiValue(13),
             RobotType::ScientificCollector => Color::AnsiValue(12),
+            RobotType::Relay => Color::AnsiValue(14),
+            RobotType::Repair => Color::AnsiValue(208),
         };
         
         stdout.execute(SetForegroundColor(robot_color))?;
@@ -186,6 +335,8 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
             RobotType::EnergyCollector => "Collecteur d'énergie",
             RobotType::MineralCollector => "Collecteur de minerais",
             RobotType::ScientificCollector => "Collecteur scientifique",
+            RobotType::Relay => "Relais",
+            RobotType::Repair => "Réparateur",
         };
         
         let mode = match robot.mode {
@@ -193,15 +344,23 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
             RobotMode::Collecting => "Collecte",
             RobotMode::ReturnToStation => "Retour",
             RobotMode::Idle => "Inactif",
+            RobotMode::Stranded => "En panne",
         };
         
-        println!("Robot #{}: {} | Pos: ({},{}) | Énergie: {:.1}/{:.1} | Mode: {} | Min: {} | Sci: {} | Exploré: {:.1}%", 
+        let radio = if robot.in_contact { "en contact" } else { "hors contact" };
+        let task = match robot.task {
+            Some((x, y)) => format!(" | Tâche: ({},{})", x, y),
+            None => String::new(),
+        };
+        
+        println!("Robot #{}: {} | Pos: ({},{}) | Énergie: {:.1}/{:.1} | Mode: {} | Soute: {} | État: {} ({} cycles) | Exploré: {:.1}% | Radio: {}{}", 
                 robot.id, robot_type, robot.x, robot.y, robot.energy, robot.max_energy, 
-                mode, robot.minerals, robot.scientific_data, robot.exploration_percentage);
+                mode, robot.inventory.summary(), robot.health.summary(), robot.health.charge_cycles,
+                robot.exploration_percentage, radio, task);
     }
     
     // Afficher la légende
-    let legend_y = station_y + 6 + state.robots_data.len() as u16;
+    let legend_y = station_y + 14 + CONFLICTS_SHOWN as u16 + state.robots_data.len() as u16;
     stdout.execute(MoveTo(0, legend_y))?;
     stdout.execute(SetForegroundColor(Color::White))?;
     println!("\n== LÉGENDE ==");
@@ -209,6 +368,9 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
     stdout.execute(SetForegroundColor(Color::Yellow))?;
     print!("[] = Station   ");
     
+    stdout.execute(SetForegroundColor(Color::DarkYellow))?;
+    print!("[] = Avant-poste   ");
+    
     stdout.execute(SetForegroundColor(Color::AnsiValue(9)))?;
     print!("E# = Explorateur   ");
     
@@ -219,7 +381,13 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
     print!("M# = Collecteur de minerais   ");
     
     stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
-    println!("S# = Collecteur scientifique");
+    print!("S# = Collecteur scientifique   ");
+    
+    stdout.execute(SetForegroundColor(Color::AnsiValue(14)))?;
+    print!("R# = Relais   ");
+    
+    stdout.execute(SetForegroundColor(Color::AnsiValue(208)))?;
+    println!("T# = Réparateur");
     
     stdout.execute(SetForegroundColor(Color::Green))?;
     print!("♦ = Énergie   ");
@@ -236,8 +404,18 @@ fn render_earth_interface(state: &SimulationState) -> Result<(), Box<dyn std::er
     stdout.execute(SetForegroundColor(Color::DarkGrey))?;
     println!("? = Non exploré");
     
-    // Instruction pour quitter
-    println!("\nAppuyez sur Ctrl+C pour quitter");
+    // Afficher les derniers événements de la station
+    if !state.events.is_empty() {
+        stdout.execute(SetForegroundColor(Color::Cyan))?;
+        println!("\n== ÉVÉNEMENTS ==");
+        stdout.execute(SetForegroundColor(Color::White))?;
+        for record in &state.events {
+            println!("[{}] {}", record.time, record.event);
+        }
+    }
+    
+    // Commandes disponibles
+    println!("\nConstruire: e/p/m/s/r/t | Annuler la dernière commande: x | Recherche: 1 optique, 2 batteries, 3 propulsion, 4 forage | Quitter: q ou Ctrl+C");
     
     stdout.flush()?;
     Ok(())
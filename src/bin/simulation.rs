@@ -1,30 +1,49 @@
 // src/bin/simulation.rs
-use ereea::types::{MAP_SIZE, RobotType, RobotMode};
-use ereea::map::Map;
+use ereea::types::{RobotType, RobotMode};
 use ereea::robot::Robot;
-use ereea::station::Station;
-use ereea::network::{SimulationState, DEFAULT_PORT, create_simulation_state};
+use ereea::network::{SimulationState, EarthCommand, DEFAULT_PORT, create_simulation_state, apply_earth_command};
+use ereea::scripting::load_script_dir;
+use ereea::sensors::SensorModel;
+use ereea::scenario::Scenario;
 
 use std::sync::{Arc, Mutex};
 use std::{thread, time::Duration};
-use tokio::net::{TcpListener, TcpStream};
-use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::sync::{mpsc, Mutex as TokioMutex};
-use std::process;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Démarrage du serveur de simulation EREEA...");
     
     // Initialisation de la simulation
+    let scenario = load_scenario();
+    
     println!("Étape 1: Initialisation de la carte...");
-    let map = Arc::new(Mutex::new(Map::new()));
+    let map = Arc::new(Mutex::new(scenario.map()));
     println!("Carte initialisée avec succès.");
     
     println!("Étape 2: Initialisation de la station...");
-    let station = Arc::new(Mutex::new(Station::new()));
+    let mut station = scenario.station();
+    scenario.place_outposts(&mut station, &mut map.lock().unwrap());
+    let station = Arc::new(Mutex::new(station));
     println!("Station initialisée avec succès.");
     
+    // Scripts de contrôle optionnels (<type>.rhai dans le dossier EREEA_SCRIPTS)
+    if let Ok(dir) = std::env::var("EREEA_SCRIPTS") {
+        let (loaded, errors) = load_script_dir(&mut station.lock().unwrap().behaviors, dir.as_ref());
+        println!("Scripts chargés depuis {}: {:?}", dir, loaded);
+        for error in errors {
+            eprintln!("Erreur de script: {}", error);
+        }
+    }
+    
+    // Capteurs bruités optionnels (erreurs de classification, occultation par les obstacles)
+    if std::env::var("EREEA_NOISY_SENSORS").is_ok() {
+        station.lock().unwrap().sensors = Some(SensorModel::default());
+    }
+    
     // Extraction des valeurs nécessaires pour créer les robots
     println!("Étape 3: Préparation des données pour la création des robots...");
     let station_x;
@@ -80,6 +99,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Étape 5: Définition du mode initial des robots...");
     for robot in robots.lock().unwrap().iter_mut() {
         robot.mode = RobotMode::Exploring;
+        robot.set_behavior(station.lock().unwrap().behaviors.create(robot.robot_type));
     }
     println!("Mode des robots configuré avec succès.");
     
@@ -88,6 +108,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let (state_tx, mut state_rx) = mpsc::channel::<SimulationState>(100);
     println!("Canaux de communication configurés avec succès.");
     
+    // Canal pour transmettre les commandes de la Terre au thread de simulation
+    let (command_tx, mut command_rx) = mpsc::unbounded_channel::<EarthCommand>();
+    
     // Canal pour signaler la fin de mission
     let (mission_complete_tx, mut mission_complete_rx) = mpsc::channel::<bool>(1);
     
@@ -99,10 +122,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     println!("Clonage des références effectué avec succès.");
     
-    let simulation_thread = thread::spawn(move || {
+    let _simulation_thread = thread::spawn(move || {
         println!("Thread de simulation démarré.");
         let mut iteration = 0;
-        let mut last_robot_creation = 0;
         let mut mission_complete = false;
         
         // Seuil d'exploration pour considérer la mission comme réussie (95%)
@@ -129,7 +151,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         if let Ok(mut robots_lock) = robots_for_sim.lock() {
                             for robot in robots_lock.iter_mut() {
                                 // Simplement changer le mode - la méthode update s'occupera du reste
-                                robot.mode = RobotMode::ReturnToStation;
+                                // (un robot en panne attend les secours)
+                                if robot.mode != RobotMode::Stranded {
+                                    robot.mode = RobotMode::ReturnToStation;
+                                }
                             }
                         }
                     }
@@ -176,24 +201,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 
                 match (robots_result, map_result, station_result) {
                     (Ok(mut robots_lock), Ok(mut map_lock), Ok(mut station_lock)) => {
+                        // Entretien de la flotte prélevé sur les réserves
+                        station_lock.pay_upkeep(robots_lock.len());
+                        
+                        station_lock.update_traffic(&robots_lock);
+                        
                         for robot in robots_lock.iter_mut() {
                             robot.update(&mut map_lock, &mut station_lock);
-                            
-                            // Si le robot est à court d'énergie, le ramener à la station
-                            if robot.energy <= 0.0 {
-                                robot.x = robot.home_station_x;
-                                robot.y = robot.home_station_y;
-                                robot.energy = robot.max_energy / 2.0;
-                                robot.mode = RobotMode::Idle;
-                            }
                         }
                         
-                        // Vérifier si la station peut créer un nouveau robot (tous les 50 cycles)
-                        // Mais seulement si la mission n'est pas complète
-                        if iteration - last_robot_creation >= 50 && !mission_complete {
-                            if let Some(new_robot) = station_lock.try_create_robot(&map_lock) {
+                        // Pannes d'énergie: secours ou abandon des robots immobilisés
+                        station_lock.handle_failures(&map_lock, &mut robots_lock);
+                        
+                        // Échanges radio entre robots proches
+                        station_lock.exchange_peer_knowledge(&mut robots_lock);
+                        
+                        // Couverture radio et transmission en direct des observations
+                        station_lock.update_communications(&map_lock, &mut robots_lock);
+                        
+                        // Attribution des collectes aux enchères
+                        station_lock.allocate_tasks(&map_lock, &mut robots_lock);
+                        
+                        // Recherche financée par les données scientifiques
+                        station_lock.update_research(&mut robots_lock);
+                        
+                        // Avant-postes: fondation, rattachement des robots et réplication
+                        station_lock.update_outposts(&mut map_lock, &mut robots_lock);
+                        
+                        // Appliquer les commandes reçues de la Terre, puis avancer la file de
+                        // construction (un robot à la fois), seulement si la mission n'est pas complète
+                        while let Ok(command) = command_rx.try_recv() {
+                            apply_earth_command(&mut station_lock, command);
+                        }
+                        if !mission_complete {
+                            if let Some(new_robot) = station_lock.advance_construction(&map_lock, &robots_lock) {
                                 robots_lock.push(new_robot);
-                                last_robot_creation = iteration;
                                 println!("Nouveau robot créé à l'itération {}", iteration);
                             }
                         }
@@ -257,7 +299,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // Utiliser TokioMutex au lieu de std::sync::Mutex pour les opérations asynchrones
     println!("Étape 9: Configuration du stockage des connexions clients...");
-    let client_streams = Arc::new(TokioMutex::new(Vec::<TcpStream>::new()));
+    let client_streams = Arc::new(TokioMutex::new(Vec::<OwnedWriteHalf>::new()));
     let client_streams_clone = client_streams.clone();
     println!("Stockage des connexions configuré avec succès.");
     
@@ -316,9 +358,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 match listener.accept().await {
                     Ok((stream, addr)) => {
                         println!("Nouvelle connexion: {}", addr);
+                        let (reader, writer) = stream.into_split();
+                        
+                        // Lire les commandes du client (une ligne JSON par commande)
+                        let commands = command_tx.clone();
+                        tokio::spawn(async move {
+                            let mut lines = BufReader::new(reader).lines();
+                            while let Ok(Some(line)) = lines.next_line().await {
+                                match serde_json::from_str::<EarthCommand>(&line) {
+                                    Ok(command) => {
+                                        if commands.send(command).is_err() {
+                                            break;
+                                        }
+                                    }
+                                    Err(e) => eprintln!("Commande invalide de {}: {:?}", addr, e),
+                                }
+                            }
+                        });
+                        
                         // Ajouter le nouveau client à la liste - avec TokioMutex c'est async-safe
                         let mut streams = client_streams.lock().await;
-                        streams.push(stream);
+                        streams.push(writer);
                         println!("Client ajouté à la liste. Nombre total de clients: {}", streams.len());
                     }
                     Err(e) => {
@@ -343,4 +403,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+// Scénario optionnel (fichier JSON indiqué par EREEA_SCENARIO): graine et économie de la station
+fn load_scenario() -> Scenario {
+    match std::env::var("EREEA_SCENARIO") {
+        Ok(path) => Scenario::load(path.as_ref()).unwrap_or_else(|error| {
+            eprintln!("Erreur de scénario: {}", error);
+            Scenario::default()
+        }),
+        Err(_) => Scenario::default(),
+    }
+}
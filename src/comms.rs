@@ -0,0 +1,162 @@
+// src/comms.rs
+// Couverture radio: la station et les relais qui lui sont reliés (directement ou en chaîne)
+// forment un réseau; les robots couverts par ce réseau transmettent leurs observations en direct.
+use crate::types::{RobotType, RobotMode, MAP_SIZE};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::pathfinding::DistanceField;
+use std::collections::HashSet;
+
+// Portée radio de la station (en cases, distance de Chebyshev)
+pub const STATION_RADIO_RANGE: usize = 5;
+// Portée radio d'un robot relais
+pub const RELAY_RADIO_RANGE: usize = 4;
+
+fn chebyshev(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0).max(a.1.abs_diff(b.1))
+}
+
+// Émetteur du réseau: la station ou un relais connecté
+#[derive(Clone, Debug)]
+pub struct RadioNode {
+    pub relay_id: Option<usize>,   // None pour la station
+    pub position: (usize, usize),
+    pub range: usize,
+}
+
+impl RadioNode {
+    pub fn covers(&self, pos: (usize, usize)) -> bool {
+        chebyshev(self.position, pos) <= self.range
+    }
+}
+
+// État du réseau radio, recalculé à chaque tour
+pub struct CommNetwork {
+    pub station_range: usize,
+    pub relay_range: usize,
+    nodes: Vec<RadioNode>,                          // Station puis relais connectés
+    connected: HashSet<usize>,                      // Robots en contact avec la station
+    out_of_contact: Vec<(usize, (usize, usize))>,   // Robots actifs (hors relais) injoignables et leur position
+    relay_count: usize,                             // Relais en service, connectés ou non
+}
+
+impl Default for CommNetwork {
+    fn default() -> Self {
+        Self::new(STATION_RADIO_RANGE, RELAY_RADIO_RANGE)
+    }
+}
+
+impl CommNetwork {
+    pub fn new(station_range: usize, relay_range: usize) -> Self {
+        Self {
+            station_range,
+            relay_range,
+            nodes: Vec::new(),
+            connected: HashSet::new(),
+            out_of_contact: Vec::new(),
+            relay_count: 0,
+        }
+    }
+
+    // Recalcule la couverture à partir de la position de la station et des robots
+    pub fn update(&mut self, station: (usize, usize), robots: &[Robot]) {
+        self.nodes = vec![RadioNode { relay_id: None, position: station, range: self.station_range }];
+
+        // Ajouter en chaîne les relais à portée d'un émetteur déjà connecté (un relais en panne n'émet plus)
+        let mut pending: Vec<&Robot> = robots.iter()
+            .filter(|r| r.robot_type == RobotType::Relay && r.mode != RobotMode::Stranded)
+            .collect();
+        self.relay_count = pending.len();
+
+        loop {
+            let before = pending.len();
+            pending.retain(|relay| {
+                let position = (relay.x, relay.y);
+                if self.nodes.iter().any(|node| node.covers(position)) {
+                    self.nodes.push(RadioNode { relay_id: Some(relay.id), position, range: self.relay_range });
+                    false
+                } else {
+                    true
+                }
+            });
+            if pending.len() == before {
+                break;
+            }
+        }
+
+        self.connected.clear();
+        self.out_of_contact.clear();
+        for robot in robots {
+            let position = (robot.x, robot.y);
+            if self.covers(position) {
+                self.connected.insert(robot.id);
+            } else if robot.robot_type != RobotType::Relay && robot.mode != RobotMode::Stranded {
+                self.out_of_contact.push((robot.id, position));
+            }
+        }
+    }
+
+    // Vérifie si une case est couverte par le réseau
+    pub fn covers(&self, pos: (usize, usize)) -> bool {
+        self.nodes.iter().any(|node| node.covers(pos))
+    }
+
+    pub fn is_connected(&self, robot_id: usize) -> bool {
+        self.connected.contains(&robot_id)
+    }
+
+    pub fn nodes(&self) -> &[RadioNode] {
+        &self.nodes
+    }
+
+    pub fn out_of_contact(&self) -> &[(usize, (usize, usize))] {
+        &self.out_of_contact
+    }
+
+    pub fn relay_count(&self) -> usize {
+        self.relay_count
+    }
+
+    // Poste d'un relais: la case accessible, couverte par le reste du réseau, la plus proche
+    // du robot injoignable le plus éloigné de la station. Sans robot à rejoindre, le relais
+    // garde sa position s'il est connecté; None s'il n'a aucun poste utile.
+    pub fn relay_post(&self, relay_id: usize, position: (usize, usize), map: &Map) -> Option<(usize, usize)> {
+        let station = self.nodes.first()?.position;
+        let target = self.out_of_contact.iter()
+            .map(|&(_, pos)| pos)
+            .max_by_key(|&pos| chebyshev(station, pos));
+
+        let target = match target {
+            Some(target) => target,
+            None if self.is_connected(relay_id) => return Some(position),
+            None => return None,
+        };
+
+        // Le relais doit rester à portée d'un émetteur placé avant lui dans la chaîne
+        // pour ne pas se couper du réseau
+        let anchors = match self.nodes.iter().position(|node| node.relay_id == Some(relay_id)) {
+            Some(index) => &self.nodes[..index],
+            None => &self.nodes[..],
+        };
+        let field = DistanceField::compute(map, &[position]);
+
+        let mut best = None;
+        let mut best_key = (usize::MAX, usize::MAX);
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                if !anchors.iter().any(|node| node.covers((x, y))) {
+                    continue;
+                }
+                if let Some(cost) = field.cost((x, y)) {
+                    let key = (chebyshev((x, y), target), cost);
+                    if key < best_key {
+                        best_key = key;
+                        best = Some((x, y));
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
@@ -0,0 +1,105 @@
+// src/construction.rs
+// File de construction de la station: les commandes (du planificateur de la station ou de la
+// Terre) sont construites une à une, dans l'ordre. La commande en tête réserve son coût dès que
+// les réserves le permettent, puis avance d'un tour de construction par tour de simulation.
+// Annuler une commande restitue les ressources réservées.
+use crate::types::RobotType;
+use crate::economy::BuildCost;
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fmt;
+
+// Origine d'une commande de construction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuildOrigin {
+    Planner,   // Besoin détecté par la station
+    Earth,     // Demandée par la Terre
+}
+
+impl fmt::Display for BuildOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildOrigin::Planner => write!(f, "station"),
+            BuildOrigin::Earth => write!(f, "Terre"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuildOrder {
+    pub id: usize,
+    pub robot_type: RobotType,
+    pub origin: BuildOrigin,
+    pub cost: BuildCost,
+    pub reserved: bool,     // Coût prélevé sur les réserves
+    pub progress: u32,      // Tours de construction effectués
+}
+
+impl BuildOrder {
+    pub fn is_complete(&self) -> bool {
+        self.reserved && self.progress >= self.cost.build_time
+    }
+}
+
+impl fmt::Display for BuildOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} {:?} ({}) ", self.id, self.robot_type, self.origin)?;
+        if self.reserved {
+            write!(f, "{}/{}", self.progress, self.cost.build_time)
+        } else {
+            write!(f, "en attente")
+        }
+    }
+}
+
+// Résumé d'une file de construction pour l'affichage
+pub fn queue_summary(orders: &[BuildOrder]) -> String {
+    if orders.is_empty() {
+        return "vide".to_string();
+    }
+    orders.iter().map(|o| o.to_string()).collect::<Vec<_>>().join(" | ")
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BuildQueue {
+    orders: VecDeque<BuildOrder>,
+    next_id: usize,
+}
+
+impl BuildQueue {
+    pub fn orders(&self) -> Vec<BuildOrder> {
+        self.orders.iter().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    pub fn head(&self) -> Option<&BuildOrder> {
+        self.orders.front()
+    }
+
+    pub(crate) fn head_mut(&mut self) -> Option<&mut BuildOrder> {
+        self.orders.front_mut()
+    }
+
+    // Ajoute une commande en fin de file; retourne son identifiant
+    pub fn push(&mut self, robot_type: RobotType, origin: BuildOrigin, cost: BuildCost) -> usize {
+        self.next_id += 1;
+        self.orders.push_back(BuildOrder {
+            id: self.next_id,
+            robot_type,
+            origin,
+            cost,
+            reserved: false,
+            progress: 0,
+        });
+        self.next_id
+    }
+
+    // Retire une commande de la file (annulation ou construction terminée)
+    pub fn remove(&mut self, id: usize) -> Option<BuildOrder> {
+        let index = self.orders.iter().position(|o| o.id == id)?;
+        self.orders.remove(index)
+    }
+}
@@ -0,0 +1,300 @@
+// src/crdt.rs
+// Connaissances répliquées entre les stations (principale et avant-postes) sous forme de CRDT.
+// Chaque case est un registre: la valeur retenue est l'observation la plus forte selon la règle de
+// résolution, départagée par la date d'observation puis par l'identifiant du robot (la dernière
+// écriture l'emporte). Cet ordre étant total, la fusion de deux cases est commutative, associative
+// et idempotente.
+// Chaque observation acceptée par une station y reçoit un point (station, numéro); l'horloge
+// vectorielle d'une réplique indique les points qu'elle a déjà intégrés. Deux répliques n'échangent
+// donc que les cases dont l'autre n'a pas encore vu le point (deltas) et convergent quel que soit
+// l'ordre des synchronisations. Chaque réplique indexe ses cases par point, si bien qu'un delta ne
+// coûte que les cases à transmettre; de leur côté, les robots notent les cases apprises depuis leur
+// dernière synchronisation et ne transmettent qu'elles.
+use crate::types::MAP_SIZE;
+use crate::station::TerrainData;
+use crate::audit::ResolutionPolicy;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+
+// Numéro de réplique de la station principale (les avant-postes portent leur numéro)
+pub const MAIN_REPLICA: usize = 0;
+
+// Fusion d'une case dans une autre: la plus forte selon la règle l'emporte.
+// Retourne true si la destination a été modifiée.
+pub fn join(destination: &mut TerrainData, source: &TerrainData, policy: ResolutionPolicy) -> bool {
+    if source.explored && (!destination.explored || policy.prefers(destination, source)) {
+        *destination = source.clone();
+        true
+    } else {
+        false
+    }
+}
+
+// Identifiant d'une écriture: réplique qui l'a acceptée et son numéro d'ordre
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Dot {
+    pub replica: usize,
+    pub counter: u64,
+}
+
+// Dernier point intégré de chaque réplique, plus les points isolés reçus au-delà (un robot qui
+// transmet par radio apprend le point de ses propres cases sans avoir vu ceux d'avant)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VectorClock {
+    entries: BTreeMap<usize, u64>,
+    cloud: BTreeSet<(usize, u64)>,
+}
+
+impl VectorClock {
+    pub fn get(&self, replica: usize) -> u64 {
+        self.entries.get(&replica).copied().unwrap_or(0)
+    }
+
+    pub fn covers(&self, dot: Dot) -> bool {
+        dot.counter <= self.get(dot.replica) || self.cloud.contains(&(dot.replica, dot.counter))
+    }
+
+    // A-t-on vu tout ce que l'autre horloge a vu ?
+    pub fn dominates(&self, other: &VectorClock) -> bool {
+        other.entries.iter().all(|(&replica, &counter)| self.get(replica) >= counter)
+            && other.cloud.iter().all(|&(replica, counter)| self.covers(Dot { replica, counter }))
+    }
+
+    pub fn replicas(&self) -> impl Iterator<Item = usize> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn merge(&mut self, other: &VectorClock) {
+        for (&replica, &counter) in other.entries.iter() {
+            let entry = self.entries.entry(replica).or_insert(0);
+            *entry = (*entry).max(counter);
+        }
+        self.cloud.extend(other.cloud.iter().copied());
+        self.compact();
+    }
+
+    // Ajoute un point vu isolément
+    pub fn add(&mut self, dot: Dot) {
+        if !self.covers(dot) {
+            self.cloud.insert((dot.replica, dot.counter));
+            self.compact();
+        }
+    }
+
+    // Intègre à la suite continue les points isolés qui la prolongent
+    fn compact(&mut self) {
+        let cloud = std::mem::take(&mut self.cloud);
+        for (replica, counter) in cloud {
+            let seen = self.get(replica);
+            if counter == seen + 1 {
+                self.entries.insert(replica, counter);
+            } else if counter > seen {
+                self.cloud.insert((replica, counter));
+            }
+        }
+    }
+
+    fn next(&mut self, replica: usize) -> Dot {
+        let counter = self.entries.entry(replica).or_insert(0);
+        *counter += 1;
+        Dot { replica, counter: *counter }
+    }
+}
+
+// Écriture transmise à une autre réplique (sans point si la case a été écrite hors des fusions)
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileOp {
+    pub position: (usize, usize),
+    pub data: TerrainData,
+    pub dot: Option<Dot>,
+}
+
+// Cases que le destinataire n'a pas encore vues, avec l'horloge de l'émetteur
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Delta {
+    pub ops: Vec<TileOp>,
+    pub clock: VectorClock,
+}
+
+impl Delta {
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    // Fusionne le delta dans la mémoire d'un robot, qui n'attribue pas de points.
+    // Retourne le nombre de cases modifiées.
+    pub fn merge_into(&self, memory: &mut [Vec<TerrainData>], clock: &mut VectorClock, policy: ResolutionPolicy) -> usize {
+        let mut updated = 0;
+        for op in self.ops.iter() {
+            let (x, y) = op.position;
+            if join(&mut memory[y][x], &op.data, policy) {
+                updated += 1;
+            }
+        }
+        clock.merge(&self.clock);
+        updated
+    }
+}
+
+// État de réplication des connaissances d'une station: point de chaque case et horloge vectorielle.
+// Les cases elles-mêmes restent dans la mémoire de la station.
+#[derive(Clone, Debug)]
+pub struct Replica {
+    pub id: usize,
+    pub clock: VectorClock,
+    dots: Vec<Vec<Option<Dot>>>,
+    writes: BTreeMap<(usize, u64), (usize, usize)>, // Case portant chaque point, dans l'ordre des points
+}
+
+impl Replica {
+    pub fn new(id: usize) -> Self {
+        Self { id, clock: VectorClock::default(), dots: vec![vec![None; MAP_SIZE]; MAP_SIZE], writes: BTreeMap::new() }
+    }
+
+    // Nouvelle réplique partant du même état (fondation d'un avant-poste)
+    pub fn fork(&self, id: usize) -> Self {
+        Self { id, clock: self.clock.clone(), dots: self.dots.clone(), writes: self.writes.clone() }
+    }
+
+    pub fn dot(&self, position: (usize, usize)) -> Option<Dot> {
+        self.dots[position.1][position.0]
+    }
+
+    // Attribue un nouveau point à une case que la station vient de modifier
+    pub fn stamp(&mut self, position: (usize, usize)) -> Dot {
+        let dot = self.clock.next(self.id);
+        self.set_dot(position, dot);
+        dot
+    }
+
+    fn set_dot(&mut self, position: (usize, usize), dot: Dot) {
+        if let Some(previous) = self.dots[position.1][position.0].replace(dot) {
+            self.writes.remove(&(previous.replica, previous.counter));
+        }
+        self.writes.insert((dot.replica, dot.counter), position);
+    }
+
+    // Intègre l'observation d'un robot; retourne true si elle a été retenue
+    pub fn ingest(&mut self, memory: &mut [Vec<TerrainData>], position: (usize, usize), data: &TerrainData,
+                  policy: ResolutionPolicy) -> bool {
+        let changed = join(&mut memory[position.1][position.0], data, policy);
+        if changed {
+            self.stamp(position);
+        }
+        changed
+    }
+
+    pub fn op(&self, memory: &[Vec<TerrainData>], position: (usize, usize)) -> TileOp {
+        TileOp { position, data: memory[position.1][position.0].clone(), dot: self.dot(position) }
+    }
+
+    // Cases dont le destinataire n'a pas vu le point, lues dans l'index des points: les cases
+    // écrites hors des fusions (sans point) ne sont pas répliquées
+    pub fn delta(&self, memory: &[Vec<TerrainData>], remote: &VectorClock) -> Delta {
+        let mut ops = Vec::new();
+        for replica in self.clock.replicas() {
+            let unseen = (Bound::Excluded((replica, remote.get(replica))), Bound::Included((replica, u64::MAX)));
+            for (&(replica, counter), &position) in self.writes.range(unseen) {
+                if !remote.covers(Dot { replica, counter }) {
+                    ops.push(self.op(memory, position));
+                }
+            }
+        }
+        Delta { ops, clock: self.clock.clone() }
+    }
+
+    // Applique le delta d'une autre réplique: chaque case retenue garde son point d'origine.
+    // Retourne le nombre de cases modifiées.
+    pub fn apply(&mut self, memory: &mut [Vec<TerrainData>], delta: &Delta, policy: ResolutionPolicy) -> usize {
+        let mut updated = 0;
+        for op in delta.ops.iter() {
+            let (x, y) = op.position;
+            if join(&mut memory[y][x], &op.data, policy) {
+                match op.dot {
+                    Some(dot) => self.set_dot(op.position, dot),
+                    None => { self.stamp(op.position); },
+                }
+                updated += 1;
+            }
+        }
+        self.clock.merge(&delta.clock);
+        updated
+    }
+
+    // Applique l'état complet d'une autre réplique en attribuant un nouveau point à chaque case
+    // modifiée
+    fn reevaluate(&mut self, memory: &mut [Vec<TerrainData>], state: &Delta, policy: ResolutionPolicy) -> usize {
+        let mut updated = 0;
+        for op in state.ops.iter() {
+            if self.ingest(memory, op.position, &op.data, policy) {
+                updated += 1;
+            }
+        }
+        self.clock.merge(&state.clock);
+        updated
+    }
+}
+
+// Synchronise deux répliques dans les deux sens; retourne le nombre de cases modifiées
+pub fn sync(a_memory: &mut [Vec<TerrainData>], a: &mut Replica, b_memory: &mut [Vec<TerrainData>], b: &mut Replica,
+            policy: ResolutionPolicy) -> usize {
+    let to_b = a.delta(a_memory, &b.clock);
+    let to_a = b.delta(b_memory, &a.clock);
+    b.apply(b_memory, &to_b, policy) + a.apply(a_memory, &to_a, policy)
+}
+
+// Synchronisation complète de deux répliques, sans tenir compte des horloges: après un changement
+// de règle, chaque case est réévaluée selon la nouvelle règle (une réplique qui a déjà vu un point
+// ne le recevrait plus par delta). Une case modifiée reçoit un nouveau point, pour que les robots
+// qui avaient vu l'ancien la reçoivent aussi. Retourne le nombre de cases modifiées.
+pub fn resync(a_memory: &mut [Vec<TerrainData>], a: &mut Replica, b_memory: &mut [Vec<TerrainData>], b: &mut Replica,
+              policy: ResolutionPolicy) -> usize {
+    let everything = VectorClock::default();
+    let to_b = a.delta(a_memory, &everything);
+    let to_a = b.delta(b_memory, &everything);
+    b.reevaluate(b_memory, &to_b, policy) + a.reevaluate(a_memory, &to_a, policy)
+}
+
+// Cases modifiées dans la mémoire d'un robot depuis sa dernière synchronisation, dans l'ordre où
+// il les a apprises
+#[derive(Clone, Debug)]
+pub struct ChangeSet {
+    marked: Vec<Vec<bool>>,
+    positions: Vec<(usize, usize)>,
+}
+
+impl Default for ChangeSet {
+    fn default() -> Self {
+        Self { marked: vec![vec![false; MAP_SIZE]; MAP_SIZE], positions: Vec::new() }
+    }
+}
+
+impl ChangeSet {
+    pub fn mark(&mut self, position: (usize, usize)) {
+        let marked = &mut self.marked[position.1][position.0];
+        if !*marked {
+            *marked = true;
+            self.positions.push(position);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    // Vide l'ensemble et retourne les cases modifiées
+    pub fn take(&mut self) -> Vec<(usize, usize)> {
+        for &(x, y) in self.positions.iter() {
+            self.marked[y][x] = false;
+        }
+        std::mem::take(&mut self.positions)
+    }
+}
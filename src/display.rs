@@ -9,11 +9,13 @@ use crate::types::{TileType, MAP_SIZE, RobotType, RobotMode};
 use crate::map::Map;
 use crate::robot::Robot;
 use crate::station::Station;
+use crate::construction::queue_summary;
+use crate::audit::ConflictQuery;
 
 pub struct Display;
 
 impl Display {
-    pub fn render(map: &Map, station: &Station, robots: &Vec<Robot>) -> Result<()> {
+    pub fn render(map: &Map, station: &Station, robots: &[Robot]) -> Result<()> {
         let mut stdout = stdout();
         
         // Effacer l'écran
@@ -30,6 +32,9 @@ impl Display {
                 if x == map.station_x && y == map.station_y {
                     stdout.execute(SetForegroundColor(Color::Yellow))?;
                     print!("[]");
+                } else if station.outposts.at((x, y)).is_some() {
+                    stdout.execute(SetForegroundColor(Color::DarkYellow))?;
+                    print!("[]");
                 } else if let Some(robot) = robot_here {
                     // Afficher le robot avec sa couleur
                     stdout.execute(SetForegroundColor(Color::AnsiValue(robot.get_display_color())))?;
@@ -71,10 +76,13 @@ impl Display {
         // Afficher les informations de la station
         stdout.execute(MoveTo(0, MAP_SIZE as u16 + 1))?;
         stdout.execute(SetForegroundColor(Color::Yellow))?;
-        println!("Station: Minerais: {} | Données Scientifiques: {} | Énergie: {}", 
+        println!("Station: Minerais: {} | Données Scientifiques: {} | Énergie: {} | Tâches: {} ouvertes, {} attribuées, {} réattribuées", 
                 station.collected_minerals, 
                 station.collected_scientific_data,
-                station.energy_reserves);
+                station.energy_reserves,
+                station.market.tasks().iter().filter(|t| t.assignee.is_none()).count(),
+                station.market.stats.awarded,
+                station.market.stats.reassigned);
         
         // Ajouter le statut de la station
         stdout.execute(MoveTo(0, MAP_SIZE as u16 + 2))?;
@@ -83,9 +91,61 @@ impl Display {
             station.get_status(),
             station.get_exploration_percentage());
         
+        // Propagation des connaissances
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 3))?;
+        println!("Connaissances: {} cases via la station | {} cases par radio | {} cases entre robots ({} rencontres) | {} répliquées | Avant-postes: {}",
+            station.knowledge_stats.tiles_via_station,
+            station.knowledge_stats.tiles_via_radio,
+            station.knowledge_stats.tiles_via_peers,
+            station.knowledge_stats.peer_encounters,
+            station.knowledge_stats.tiles_via_replication,
+            station.outposts.sites.len());
+        
+        // Circulation des robots
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 4))?;
+        println!("Circulation: {} attentes | {} détours | {} interblocages résolus | Quais: {}, {} attentes, {} énergie délivrée",
+            station.traffic.stats.blocked_moves,
+            station.traffic.stats.detours,
+            station.traffic.stats.deadlocks_resolved,
+            station.docking.summary(station.economy.docking_bays),
+            station.docking.stats.queue_waits,
+            station.docking.stats.energy_delivered);
+        
+        // Pannes et secours
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 5))?;
+        println!("Pannes: {} | {} relancés | {} remorqués | {} abandonnés | Recharges sur le terrain: {}",
+            station.rescue.stats.stranded,
+            station.rescue.stats.recharged,
+            station.rescue.stats.towed,
+            station.rescue.stats.written_off,
+            station.rescue.stats.field_charges);
+        
+        // Usure et entretien
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 6))?;
+        println!("Usure: {} défaillances | {} réparés sur le terrain | {} révisés à quai | {} batteries remplacées",
+            station.maintenance.stats.failures,
+            station.maintenance.stats.field_repairs,
+            station.maintenance.stats.station_repairs,
+            station.maintenance.stats.batteries_replaced);
+        
+        // File de construction
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 7))?;
+        let plan = station.last_plan.as_ref().map(|p| p.to_string()).unwrap_or_default();
+        println!("Construction: {} | Planification: {}", queue_summary(&station.construction.orders()), plan);
+        
+        // Recherche
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 8))?;
+        println!("Recherche: {}", station.research.summary());
+        
+        // Dernier désaccord consigné au journal d'audit
+        stdout.execute(MoveTo(0, MAP_SIZE as u16 + 9))?;
+        let last = station.audit.query(&ConflictQuery { limit: 1, ..ConflictQuery::default() });
+        println!("Conflits: {} (règle: {}) | Dernier: {}", station.audit.total(), station.merge_policy,
+            last.first().map(|c| c.to_string()).unwrap_or_else(|| "aucun".to_string()));
+        
         // Afficher les informations de chaque robot
         for (i, robot) in robots.iter().enumerate() {
-            stdout.execute(MoveTo(0, MAP_SIZE as u16 + 4 + i as u16))?;
+            stdout.execute(MoveTo(0, MAP_SIZE as u16 + 10 + i as u16))?;
             stdout.execute(SetForegroundColor(Color::AnsiValue(robot.get_display_color())))?;
             
             let robot_type = match robot.robot_type {
@@ -93,6 +153,8 @@ impl Display {
                 RobotType::EnergyCollector => "Collecteur d'énergie",
                 RobotType::MineralCollector => "Collecteur de minerais",
                 RobotType::ScientificCollector => "Collecteur scientifique",
+                RobotType::Relay => "Relais",
+                RobotType::Repair => "Réparateur",
             };
             
             let mode = match robot.mode {
@@ -100,15 +162,18 @@ impl Display {
                 RobotMode::Collecting => "Collecte",
                 RobotMode::ReturnToStation => "Retour",
                 RobotMode::Idle => "Inactif",
+                RobotMode::Stranded => "En panne",
             };
             
-            println!("Robot #{}: {} | Énergie: {:.1}/{:.1} | Mode: {} | Min: {} | Sci: {} | Exploré: {:.1}%", 
+            let radio = if robot.in_contact { "en contact" } else { "hors contact" };
+            
+            println!("Robot #{}: {} | Énergie: {:.1}/{:.1} | Mode: {} | Soute: {} | État: {} | Exploré: {:.1}% | Radio: {}", 
                     robot.id, robot_type, robot.energy, robot.max_energy, mode, 
-                    robot.minerals, robot.scientific_data, robot.get_exploration_percentage());
+                    robot.inventory.summary(), robot.health.summary(), robot.get_exploration_percentage(), radio);
         }
         
         // Afficher la légende
-        let legend_y = MAP_SIZE as u16 + 4 + robots.len() as u16 + 1;
+        let legend_y = MAP_SIZE as u16 + 10 + robots.len() as u16 + 1;
         stdout.execute(MoveTo(0, legend_y))?;
         stdout.execute(SetForegroundColor(Color::White))?;
         println!("Légende :");
@@ -117,6 +182,9 @@ impl Display {
         stdout.execute(SetForegroundColor(Color::Yellow))?;
         print!("[] = Station   ");
         
+        stdout.execute(SetForegroundColor(Color::DarkYellow))?;
+        print!("[] = Avant-poste   ");
+        
         stdout.execute(SetForegroundColor(Color::AnsiValue(9)))?;
         print!("E# = Explorateur   ");
         
@@ -127,7 +195,13 @@ impl Display {
         print!("M# = Collecteur de minerais   ");
         
         stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
-        println!("S# = Collecteur scientifique");
+        print!("S# = Collecteur scientifique   ");
+        
+        stdout.execute(SetForegroundColor(Color::AnsiValue(14)))?;
+        print!("R# = Relais   ");
+        
+        stdout.execute(SetForegroundColor(Color::AnsiValue(208)))?;
+        println!("T# = Réparateur");
         
         stdout.execute(MoveTo(0, legend_y + 2))?;
         stdout.execute(SetForegroundColor(Color::Green))?;
@@ -0,0 +1,92 @@
+// src/docking.rs
+// Quais des stations: un nombre limité de baies de recharge par station. Un robot qui rentre
+// demande une baie au moment d'entrer sur le quai; s'il n'y en a pas de libre, il attend à côté
+// de la station, dans l'ordre d'arrivée. Quand les réserves du quai sont trop basses pour
+// recharger, les robots qui rapportent de l'énergie (cellules, minerais) passent en premier.
+// Un robot n'occupe sa baie que le temps de sa recharge: une fois chargé, il peut rester sur le
+// quai sans bloquer les autres.
+use crate::robot::Robot;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+// Statistiques des quais
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockingStats {
+    pub queue_waits: usize,     // Tours passés à attendre une baie libre
+    pub longest_queue: usize,   // Plus longue file d'attente observée à un quai
+    pub energy_delivered: u32,  // Énergie prélevée sur les réserves pour recharger les robots
+}
+
+// Robot en attente d'une baie
+#[derive(Clone, Copy, Debug)]
+struct Waiting {
+    robot_id: usize,
+    arrival: u32,       // Première demande
+    last_request: u32,  // Dernière demande (la file oublie les robots qui ne demandent plus)
+    priority: bool,
+}
+
+#[derive(Default)]
+pub struct Docking {
+    docked: HashMap<(usize, usize), Vec<usize>>,    // Quai -> robots dans ses baies
+    queues: HashMap<(usize, usize), Vec<Waiting>>,  // Quai -> robots en attente
+    pub stats: DockingStats,
+}
+
+impl Docking {
+    // Baies occupées en début de tour; la file ne garde que les demandes récentes
+    pub fn sync(&mut self, robots: &[Robot], now: u32) {
+        self.docked.clear();
+        for robot in robots.iter().filter(|r| r.is_at_station() && r.charging) {
+            self.docked.entry((robot.x, robot.y)).or_default().push(robot.id);
+        }
+        for queue in self.queues.values_mut() {
+            queue.retain(|w| w.last_request + 1 >= now);
+        }
+    }
+
+    pub fn docked_at(&self, dock: (usize, usize)) -> usize {
+        self.docked.get(&dock).map_or(0, |robots| robots.len())
+    }
+
+    // Robots en attente à un quai, dans leur ordre de passage
+    pub fn queue_at(&self, dock: (usize, usize)) -> Vec<usize> {
+        let mut queue = self.queues.get(&dock).cloned().unwrap_or_default();
+        queue.sort_by_key(|w| (!w.priority, w.arrival, w.robot_id));
+        queue.into_iter().map(|w| w.robot_id).collect()
+    }
+
+    // Demande d'une baie: accordée si une baie est libre et que le robot est le premier de la file
+    pub fn request(&mut self, robot_id: usize, dock: (usize, usize), bays: usize, priority: bool, now: u32) -> bool {
+        let queue = self.queues.entry(dock).or_default();
+        match queue.iter_mut().find(|w| w.robot_id == robot_id) {
+            Some(waiting) => {
+                waiting.last_request = now;
+                waiting.priority = priority;
+            },
+            None => queue.push(Waiting { robot_id, arrival: now, last_request: now, priority }),
+        }
+
+        let first = self.queue_at(dock).first().copied();
+        let docked = self.docked.entry(dock).or_default();
+        if docked.len() < bays && first == Some(robot_id) {
+            docked.push(robot_id);
+            if let Some(queue) = self.queues.get_mut(&dock) {
+                queue.retain(|w| w.robot_id != robot_id);
+            }
+            return true;
+        }
+
+        self.stats.queue_waits += 1;
+        let length = self.queues.get(&dock).map_or(0, |q| q.len());
+        self.stats.longest_queue = self.stats.longest_queue.max(length);
+        false
+    }
+
+    // Résumé de l'occupation de tous les quais pour l'affichage
+    pub fn summary(&self, bays: usize) -> String {
+        let docked: usize = self.docked.values().map(|robots| robots.len()).sum();
+        let waiting: usize = self.queues.values().map(|queue| queue.len()).sum();
+        format!("{} robots à quai ({} baies par quai), {} en attente", docked, bays, waiting)
+    }
+}
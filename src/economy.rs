@@ -0,0 +1,105 @@
+// src/economy.rs
+// Économie de la station: réserves de départ, coût et durée de construction de chaque type de
+// robot, conversion des ressources déposées en énergie, entretien de la flotte et recharge aux
+// quais. Les valeurs par défaut sont celles de la mission standard; un scénario peut les
+// remplacer.
+use crate::types::RobotType;
+use crate::inventory::ENERGY_PER_CELL;
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, VecDeque};
+
+// Nombre de tours pris en compte dans les tendances des réserves
+pub const TREND_WINDOW: usize = 100;
+
+// Coût de construction d'un robot
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuildCost {
+    pub energy: u32,
+    pub minerals: u32,
+    pub build_time: u32,    // Tours de construction
+}
+
+impl Default for BuildCost {
+    fn default() -> Self {
+        Self { energy: 50, minerals: 15, build_time: 50 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Economy {
+    pub starting_energy: u32,
+    pub starting_minerals: u32,
+    pub mineral_energy: u32,       // Énergie tirée de chaque minerai déposé
+    pub cell_energy: u32,          // Énergie tirée de chaque cellule déposée
+    pub upkeep_per_robot: f32,     // Énergie consommée par robot et par tour
+    pub docking_bays: usize,       // Baies de recharge par station
+    pub charge_rate: u32,          // Énergie délivrée par baie et par tour, prise sur les réserves
+    pub build_costs: HashMap<RobotType, BuildCost>, // Coûts propres à certains types de robot
+    pub default_build_cost: BuildCost,              // Coût des autres types
+}
+
+impl Default for Economy {
+    fn default() -> Self {
+        Self {
+            starting_energy: 100,
+            starting_minerals: 0,
+            mineral_energy: 1,
+            cell_energy: ENERGY_PER_CELL,
+            upkeep_per_robot: 0.0,
+            docking_bays: 3,
+            charge_rate: 10,
+            build_costs: HashMap::new(),
+            default_build_cost: BuildCost::default(),
+        }
+    }
+}
+
+impl Economy {
+    // Coût de construction d'un type de robot
+    pub fn build_cost(&self, robot_type: RobotType) -> &BuildCost {
+        self.build_costs.get(&robot_type).unwrap_or(&self.default_build_cost)
+    }
+}
+
+// Tendance des réserves: recettes (dépôts) moins dépenses courantes (entretien, pièces) de chaque
+// tour, moyennées sur les derniers tours. Les constructions, qui sont des investissements
+// ponctuels, n'y figurent pas
+#[derive(Clone, Debug, Default)]
+pub struct ResourceTrend {
+    samples: VecDeque<(i64, i64)>,  // Bilan (énergie, minerais) des tours écoulés
+    current: (i64, i64),            // Bilan du tour en cours
+}
+
+impl ResourceTrend {
+    pub fn record(&mut self, energy: i64, minerals: i64) {
+        self.current.0 += energy;
+        self.current.1 += minerals;
+    }
+
+    // Clôt le bilan du tour en cours
+    pub fn close_tick(&mut self) {
+        if self.samples.len() == TREND_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(self.current);
+        self.current = (0, 0);
+    }
+
+    // Variation moyenne de l'énergie par tour
+    pub fn energy_rate(&self) -> f32 {
+        self.rate(|sample| sample.0)
+    }
+
+    // Variation moyenne des minerais par tour
+    pub fn mineral_rate(&self) -> f32 {
+        self.rate(|sample| sample.1)
+    }
+
+    fn rate(&self, part: impl Fn(&(i64, i64)) -> i64) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().map(part).sum::<i64>() as f32 / self.samples.len() as f32
+    }
+}
@@ -0,0 +1,115 @@
+use crate::rescue::RescueMethod;
+use crate::wear::Component;
+use crate::types::RobotType;
+use crate::research::Technology;
+use serde::{Serialize, Deserialize};
+use std::collections::VecDeque;
+use std::fmt;
+
+// Nombre d'événements conservés par la station
+pub const EVENT_LOG_CAPACITY: usize = 200;
+
+// Événements notables de la simulation, transmis à la Terre
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SimulationEvent {
+    // Le script qui pilote un robot a échoué (erreur de syntaxe, d'exécution ou budget dépassé)
+    ScriptError { robot_id: usize, script: String, message: String },
+    // Un robot est tombé en panne d'énergie
+    RobotStranded { robot_id: usize, position: (usize, usize) },
+    // Un robot en panne a été secouru
+    RobotRescued { robot_id: usize, rescuer_id: usize, method: RescueMethod },
+    // Un robot en panne n'a pas été secouru à temps et est abandonné
+    RobotWrittenOff { robot_id: usize, position: (usize, usize) },
+    // Un collecteur d'énergie a rechargé un robot sur le terrain, à sa demande
+    RobotCharged { robot_id: usize, charger_id: usize, energy: f32 },
+    // Un composant usé d'un robot a défailli
+    ComponentFailure { robot_id: usize, component: Component },
+    // Un robot de réparation a remis un robot en état sur le terrain
+    RobotRepaired { robot_id: usize, repairer_id: usize },
+    // La station a terminé la construction d'un robot
+    RobotBuilt { robot_id: usize, robot_type: RobotType },
+    // La station a acquis un niveau de technologie
+    ResearchCompleted { technology: Technology, level: u32 },
+    // Un avant-poste a été fondé (par un robot, ou placé par le scénario)
+    OutpostFounded { outpost_id: usize, position: (usize, usize), founder: Option<usize> },
+}
+
+impl fmt::Display for SimulationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationEvent::ScriptError { robot_id, script, message } => {
+                write!(f, "Robot #{}: erreur du script '{}': {}", robot_id, script, message)
+            }
+            SimulationEvent::RobotStranded { robot_id, position } => {
+                write!(f, "Robot #{}: panne d'énergie en ({},{})", robot_id, position.0, position.1)
+            }
+            SimulationEvent::RobotRescued { robot_id, rescuer_id, method } => {
+                write!(f, "Robot #{}: secouru par le robot #{} ({})", robot_id, rescuer_id, method)
+            }
+            SimulationEvent::RobotWrittenOff { robot_id, position } => {
+                write!(f, "Robot #{}: abandonné en ({},{}) faute de secours", robot_id, position.0, position.1)
+            }
+            SimulationEvent::RobotCharged { robot_id, charger_id, energy } => {
+                write!(f, "Robot #{}: rechargé de {:.1} sur le terrain par le robot #{}", robot_id, energy, charger_id)
+            }
+            SimulationEvent::ComponentFailure { robot_id, component } => {
+                write!(f, "Robot #{}: défaillance ({})", robot_id, component)
+            }
+            SimulationEvent::RobotRepaired { robot_id, repairer_id } => {
+                write!(f, "Robot #{}: remis en état par le robot #{}", robot_id, repairer_id)
+            }
+            SimulationEvent::RobotBuilt { robot_id, robot_type } => {
+                write!(f, "Robot #{}: construit ({:?})", robot_id, robot_type)
+            }
+            SimulationEvent::ResearchCompleted { technology, level } => {
+                write!(f, "Station: {} niveau {} acquis", technology, level)
+            }
+            SimulationEvent::OutpostFounded { outpost_id, position, founder: Some(robot_id) } => {
+                write!(f, "Avant-poste #{}: fondé en ({},{}) par le robot #{}", outpost_id, position.0, position.1, robot_id)
+            }
+            SimulationEvent::OutpostFounded { outpost_id, position, founder: None } => {
+                write!(f, "Avant-poste #{}: établi en ({},{})", outpost_id, position.0, position.1)
+            }
+        }
+    }
+}
+
+// Événement horodaté avec l'horloge de la station
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub time: u32,
+    pub event: SimulationEvent,
+}
+
+// Journal circulaire des derniers événements
+#[derive(Clone, Default)]
+pub struct EventLog {
+    records: VecDeque<EventRecord>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, time: u32, event: SimulationEvent) {
+        if self.records.len() == EVENT_LOG_CAPACITY {
+            self.records.pop_front();
+        }
+        self.records.push_back(EventRecord { time, event });
+    }
+
+    // Les `count` derniers événements, du plus ancien au plus récent
+    pub fn recent(&self, count: usize) -> Vec<EventRecord> {
+        let skip = self.records.len().saturating_sub(count);
+        self.records.iter().skip(skip).cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
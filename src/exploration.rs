@@ -0,0 +1,149 @@
+use crate::types::MAP_SIZE;
+use crate::map::Map;
+use crate::movement::STEP_COST;
+use crate::pathfinding::DistanceField;
+use crate::station::TerrainData;
+use crate::fov::in_range;
+use std::collections::VecDeque;
+
+// Un groupe de cases frontières connexes (limite connu / inconnu)
+#[derive(Clone, Debug)]
+pub struct Frontier {
+    pub cells: Vec<(usize, usize)>,
+    pub target: (usize, usize),    // Case du groupe visée par le robot (la plus proche du centre)
+    pub information_gain: usize,   // Nombre de cases inconnues visibles depuis la cible
+}
+
+// Candidat évalué pour un robot donné
+#[derive(Clone, Debug)]
+pub struct FrontierCandidate {
+    pub frontier: Frontier,
+    pub travel_cost: usize,        // Coût du trajet robot -> cible (unités STEP_COST)
+    pub return_cost: usize,        // Coût du trajet cible -> station (unités STEP_COST)
+    pub score: f32,
+}
+
+// Itère sur les 8 voisins d'une case dans la grille
+fn grid_neighbors(pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    (-1..=1isize).flat_map(move |dy| (-1..=1isize).map(move |dx| (dx, dy)))
+        .filter(|&(dx, dy)| dx != 0 || dy != 0)
+        .filter_map(move |(dx, dy)| {
+            let nx = pos.0 as isize + dx;
+            let ny = pos.1 as isize + dy;
+            if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+}
+
+// Une case frontière est une case connue et praticable qui touche une case inconnue
+pub fn is_frontier_cell(memory: &[Vec<TerrainData>], map: &Map, pos: (usize, usize)) -> bool {
+    memory[pos.1][pos.0].explored
+        && map.is_valid_position(pos.0, pos.1)
+        && grid_neighbors(pos).any(|(nx, ny)| !memory[ny][nx].explored)
+}
+
+// Nombre de cases inconnues à portée de vision d'une position (cercle de vision; les obstacles
+// encore inconnus ne peuvent pas être anticipés)
+pub fn information_gain(memory: &[Vec<TerrainData>], pos: (usize, usize), vision_range: usize) -> usize {
+    let min_x = pos.0.saturating_sub(vision_range);
+    let min_y = pos.1.saturating_sub(vision_range);
+    let max_x = (pos.0 + vision_range).min(MAP_SIZE - 1);
+    let max_y = (pos.1 + vision_range).min(MAP_SIZE - 1);
+
+    let mut gain = 0;
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let (dx, dy) = (x as isize - pos.0 as isize, y as isize - pos.1 as isize);
+            if in_range(dx, dy, vision_range) && !memory[y][x].explored {
+                gain += 1;
+            }
+        }
+    }
+    gain
+}
+
+// Détecte les frontières de la mémoire d'un robot et les regroupe par connexité
+pub fn find_frontiers(memory: &[Vec<TerrainData>], map: &Map, vision_range: usize) -> Vec<Frontier> {
+    let mut visited = vec![vec![false; MAP_SIZE]; MAP_SIZE];
+    let mut frontiers = Vec::new();
+
+    for y in 0..MAP_SIZE {
+        for x in 0..MAP_SIZE {
+            if visited[y][x] || !is_frontier_cell(memory, map, (x, y)) {
+                continue;
+            }
+
+            // Regrouper les cases frontières voisines (BFS)
+            let mut cells = Vec::new();
+            let mut queue = VecDeque::new();
+            visited[y][x] = true;
+            queue.push_back((x, y));
+
+            while let Some(pos) = queue.pop_front() {
+                cells.push(pos);
+                for (nx, ny) in grid_neighbors(pos) {
+                    if !visited[ny][nx] && is_frontier_cell(memory, map, (nx, ny)) {
+                        visited[ny][nx] = true;
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+
+            // Cible: la case du groupe la plus proche du centroïde
+            let cx = cells.iter().map(|c| c.0).sum::<usize>() as f32 / cells.len() as f32;
+            let cy = cells.iter().map(|c| c.1).sum::<usize>() as f32 / cells.len() as f32;
+            let target = *cells.iter()
+                .min_by(|a, b| {
+                    let da = (a.0 as f32 - cx).powi(2) + (a.1 as f32 - cy).powi(2);
+                    let db = (b.0 as f32 - cx).powi(2) + (b.1 as f32 - cy).powi(2);
+                    da.total_cmp(&db)
+                })
+                .unwrap();
+
+            frontiers.push(Frontier {
+                information_gain: information_gain(memory, target, vision_range),
+                cells,
+                target,
+            });
+        }
+    }
+
+    frontiers
+}
+
+// Évalue les frontières pour un robot: gain d'information pondéré par la distance,
+// en écartant celles dont l'aller-retour dépasse le budget énergétique.
+// `energy_needed` donne l'énergie à engager pour un aller puis un retour de coûts donnés.
+pub fn rank_frontiers(
+    frontiers: Vec<Frontier>,
+    map: &Map,
+    start: (usize, usize),
+    home: (usize, usize),
+    energy_budget: f32,
+    energy_needed: impl Fn(usize, usize) -> f32,
+) -> Vec<FrontierCandidate> {
+    // Un seul Dijkstra depuis le robot donne le coût vers toutes les cibles
+    let from_robot = DistanceField::compute(map, &[start]);
+
+    let mut candidates: Vec<FrontierCandidate> = frontiers.into_iter()
+        .filter_map(|frontier| {
+            let travel_cost = from_robot.cost(frontier.target)?;
+            let return_cost = map.travel_cost_to_goal(frontier.target, home)?;
+
+            // Budget de retour: il faut pouvoir atteindre la frontière puis rentrer
+            if energy_needed(travel_cost, return_cost) > energy_budget {
+                return None;
+            }
+
+            let travel_tiles = travel_cost as f32 / STEP_COST as f32;
+            let score = frontier.information_gain as f32 / (1.0 + travel_tiles);
+            Some(FrontierCandidate { frontier, travel_cost, return_cost, score })
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates
+}
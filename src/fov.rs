@@ -0,0 +1,110 @@
+// src/fov.rs
+// Champ de vision des robots: portée circulaire et ombre portée des obstacles (shadowcasting
+// récursif par octants). Les obstacles sont visibles mais masquent les cases situées derrière.
+use crate::types::{TileType, MAP_SIZE};
+use crate::map::Map;
+
+// Transformations (xx, xy, yx, yy) ramenant chaque octant au premier
+const OCTANTS: [(isize, isize, isize, isize); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+// Case à portée d'un cercle de rayon `range` (arrondi pour inclure les diagonales au rayon 1)
+pub fn in_range(dx: isize, dy: isize, range: usize) -> bool {
+    let range = range as isize;
+    dx * dx + dy * dy <= range * range + range
+}
+
+// Cases visibles depuis `origin` (origine comprise), ligne par ligne
+pub fn field_of_view(map: &Map, origin: (usize, usize), range: usize) -> Vec<(usize, usize)> {
+    let side = 2 * range + 1;
+    let mut scan = Scan { map, origin, range, seen: vec![false; side * side], visible: Vec::new() };
+    scan.mark(0, 0, origin);
+
+    for octant in OCTANTS {
+        scan.cast_light(1, 1.0, 0.0, octant);
+    }
+
+    scan.visible.sort_unstable_by_key(|&(x, y)| (y, x));
+    scan.visible
+}
+
+struct Scan<'a> {
+    map: &'a Map,
+    origin: (usize, usize),
+    range: usize,
+    seen: Vec<bool>,                // Fenêtre (2r+1)² centrée sur l'origine: case déjà retenue
+    visible: Vec<(usize, usize)>,
+}
+
+impl Scan<'_> {
+    // Retient une case visible (les octants se recouvrent sur les axes et les diagonales)
+    fn mark(&mut self, dx: isize, dy: isize, position: (usize, usize)) {
+        let side = 2 * self.range as isize + 1;
+        let index = ((dy + self.range as isize) * side + dx + self.range as isize) as usize;
+        if !self.seen[index] {
+            self.seen[index] = true;
+            self.visible.push(position);
+        }
+    }
+
+    // Balaye un octant ligne par ligne entre deux pentes; un obstacle ouvre une nouvelle passe
+    // pour la partie encore éclairée et réduit la pente de départ des lignes suivantes
+    fn cast_light(&mut self, row: usize, mut start: f32, end: f32, octant: (isize, isize, isize, isize)) {
+        if start < end {
+            return;
+        }
+
+        let (xx, xy, yx, yy) = octant;
+        let mut new_start = 0.0;
+        for distance in row..=self.range {
+            let dy = -(distance as isize);
+            let mut blocked = false;
+
+            for dx in dy..=0 {
+                let left_slope = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                let right_slope = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+                if start < right_slope {
+                    continue;
+                }
+                if end > left_slope {
+                    break;
+                }
+
+                // Hors de la carte: opaque
+                let x = self.origin.0 as isize + dx * xx + dy * xy;
+                let y = self.origin.1 as isize + dx * yx + dy * yy;
+                let inside = x >= 0 && x < MAP_SIZE as isize && y >= 0 && y < MAP_SIZE as isize;
+                let opaque = !inside || self.map.get_tile(x as usize, y as usize) == TileType::Obstacle;
+
+                if inside && in_range(dx, dy, self.range) {
+                    self.mark(x - self.origin.0 as isize, y - self.origin.1 as isize, (x as usize, y as usize));
+                }
+
+                if blocked {
+                    if opaque {
+                        new_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = new_start;
+                    }
+                } else if opaque && distance < self.range {
+                    blocked = true;
+                    self.cast_light(distance + 1, start, left_slope, octant);
+                    new_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// src/inventory.rs
+// Soute des robots: chaque type de robot dispose d'emplacements typés (une ressource, une
+// capacité, une masse par unité) définis dans `cargo_slots`. La masse transportée alourdit
+// les déplacements; le robot rentre décharger dès qu'un emplacement est plein.
+use crate::types::{RobotType, TileType};
+use serde::{Serialize, Deserialize};
+
+// Énergie contenue dans une cellule récoltée par un collecteur d'énergie
+pub const ENERGY_PER_CELL: u32 = 10;
+
+// Surcoût de déplacement par unité de masse transportée (proportion du coût à vide)
+pub const MASS_MOVE_PENALTY: f32 = 0.02;
+
+// Ressource transportable
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CargoKind {
+    Mineral,
+    ScientificData,
+    EnergyCell,
+}
+
+impl CargoKind {
+    // Ressource récoltée sur une case
+    pub fn from_tile(tile: &TileType) -> Option<Self> {
+        match tile {
+            TileType::Mineral => Some(CargoKind::Mineral),
+            TileType::Scientific => Some(CargoKind::ScientificData),
+            TileType::Energy => Some(CargoKind::EnergyCell),
+            _ => None,
+        }
+    }
+
+    // Case où cette ressource se récolte
+    pub fn tile(self) -> TileType {
+        match self {
+            CargoKind::Mineral => TileType::Mineral,
+            CargoKind::ScientificData => TileType::Scientific,
+            CargoKind::EnergyCell => TileType::Energy,
+        }
+    }
+
+    // Libellé court pour l'affichage
+    pub fn label(self) -> &'static str {
+        match self {
+            CargoKind::Mineral => "Min",
+            CargoKind::ScientificData => "Sci",
+            CargoKind::EnergyCell => "Cellules",
+        }
+    }
+}
+
+// Emplacement de soute et son contenu
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CargoSlot {
+    pub kind: CargoKind,
+    pub capacity: u32,
+    pub unit_mass: f32,
+    pub amount: u32,
+}
+
+impl CargoSlot {
+    fn new(kind: CargoKind, capacity: u32, unit_mass: f32) -> Self {
+        Self { kind, capacity, unit_mass, amount: 0 }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.amount >= self.capacity
+    }
+}
+
+// Emplacements de soute de chaque type de robot
+pub fn cargo_slots(robot_type: RobotType) -> Vec<CargoSlot> {
+    match robot_type {
+        RobotType::EnergyCollector => vec![CargoSlot::new(CargoKind::EnergyCell, 5, 1.0)],
+        RobotType::MineralCollector => vec![CargoSlot::new(CargoKind::Mineral, 5, 2.0)],
+        RobotType::ScientificCollector => vec![CargoSlot::new(CargoKind::ScientificData, 3, 0.5)],
+        RobotType::Explorer | RobotType::Relay | RobotType::Repair => Vec::new(),
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    slots: Vec<CargoSlot>,
+}
+
+impl Inventory {
+    // Soute vide d'un type de robot
+    pub fn for_robot(robot_type: RobotType) -> Self {
+        Self { slots: cargo_slots(robot_type) }
+    }
+
+    pub fn slots(&self) -> &[CargoSlot] {
+        &self.slots
+    }
+
+    fn slot_mut(&mut self, kind: CargoKind) -> Option<&mut CargoSlot> {
+        self.slots.iter_mut().find(|s| s.kind == kind)
+    }
+
+    // Ressource que le robot est équipé pour récolter (premier emplacement)
+    pub fn primary(&self) -> Option<CargoKind> {
+        self.slots.first().map(|s| s.kind)
+    }
+
+    pub fn amount(&self, kind: CargoKind) -> u32 {
+        self.slots.iter().find(|s| s.kind == kind).map_or(0, |s| s.amount)
+    }
+
+    pub fn capacity(&self, kind: CargoKind) -> u32 {
+        self.slots.iter().find(|s| s.kind == kind).map_or(0, |s| s.capacity)
+    }
+
+    // Ajoute jusqu'à `count` unités; retourne le nombre effectivement chargé
+    pub fn add(&mut self, kind: CargoKind, count: u32) -> u32 {
+        match self.slot_mut(kind) {
+            Some(slot) => {
+                let added = count.min(slot.capacity.saturating_sub(slot.amount));
+                slot.amount += added;
+                added
+            },
+            None => 0,
+        }
+    }
+
+    // Retire jusqu'à `count` unités; retourne le nombre effectivement retiré
+    pub fn take(&mut self, kind: CargoKind, count: u32) -> u32 {
+        match self.slot_mut(kind) {
+            Some(slot) => {
+                let taken = count.min(slot.amount);
+                slot.amount -= taken;
+                taken
+            },
+            None => 0,
+        }
+    }
+
+    // Un emplacement plein suffit à rendre la soute pleine
+    pub fn is_full(&self) -> bool {
+        self.slots.iter().any(|s| s.is_full())
+    }
+
+    // Taux de remplissage (0 à 1) de l'emplacement le plus chargé
+    pub fn load(&self) -> f32 {
+        self.slots.iter()
+            .filter(|s| s.capacity > 0)
+            .map(|s| s.amount as f32 / s.capacity as f32)
+            .fold(0.0, f32::max)
+    }
+
+    // Masse transportée
+    pub fn mass(&self) -> f32 {
+        self.slots.iter().map(|s| s.amount as f32 * s.unit_mass).sum()
+    }
+
+    // Nombre total d'unités transportées
+    pub fn total_units(&self) -> u32 {
+        self.slots.iter().map(|s| s.amount).sum()
+    }
+
+    // Résumé du chargement, ex. "Min 2/5"
+    pub fn summary(&self) -> String {
+        if self.slots.is_empty() {
+            return "-".to_string();
+        }
+        self.slots.iter()
+            .map(|s| format!("{} {}/{}", s.kind.label(), s.amount, s.capacity))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
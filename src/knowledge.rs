@@ -0,0 +1,140 @@
+use crate::types::{RobotMode, MAP_SIZE};
+use crate::robot::Robot;
+use crate::station::TerrainData;
+use crate::audit::ResolutionPolicy;
+use crate::crdt::join;
+use serde::{Serialize, Deserialize};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+// Portée radio par défaut entre deux robots (en cases, distance de Chebyshev)
+pub const DEFAULT_RADIO_RANGE: usize = 3;
+// Nombre maximal de cases transmises dans chaque sens lors d'une rencontre
+pub const DEFAULT_PEER_BANDWIDTH: usize = 40;
+
+// Bonus de fraîcheur par tour d'écart entre deux observations lors d'une fusion
+pub const RECENCY_WEIGHT: f32 = 0.01;
+
+// Poids d'une observation: sa confiance, plus un bonus de fraîcheur
+pub(crate) fn merge_weight(data: &TerrainData) -> f32 {
+    data.confidence + RECENCY_WEIGHT * data.timestamp as f32
+}
+
+// Fusion d'une case: l'observation la plus lourde l'emporte (règle par défaut des stations); avec
+// des capteurs parfaits, c'est la plus récente. Retourne true si la destination a été modifiée.
+pub fn merge_tile(destination: &mut TerrainData, source: &TerrainData) -> bool {
+    join(destination, source, ResolutionPolicy::Weighted)
+}
+
+// Statistiques de propagation des connaissances
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeStats {
+    pub tiles_via_station: usize,   // Cases mises à jour lors des synchronisations à la station
+    pub tiles_via_peers: usize,     // Cases mises à jour lors des échanges entre robots
+    pub tiles_via_radio: usize,     // Cases transmises en direct par les robots à portée radio
+    pub peer_encounters: usize,     // Nombre de rencontres radio entre robots
+    pub tiles_via_replication: usize, // Cases répliquées entre la station et les avant-postes
+}
+
+// Échanges de connaissances entre robots à portée radio
+pub struct PeerExchange {
+    pub radio_range: usize,
+    pub bandwidth: usize,
+    // Rencontres en cours: (id le plus petit, id le plus grand) -> cases déjà transmises dans chaque sens
+    encounters: HashMap<(usize, usize), (usize, usize)>,
+}
+
+impl Default for PeerExchange {
+    fn default() -> Self {
+        Self::new(DEFAULT_RADIO_RANGE, DEFAULT_PEER_BANDWIDTH)
+    }
+}
+
+impl PeerExchange {
+    pub fn new(radio_range: usize, bandwidth: usize) -> Self {
+        Self { radio_range, bandwidth, encounters: HashMap::new() }
+    }
+
+    // Un robot en panne n'a plus d'énergie pour émettre
+    fn in_range(&self, a: &Robot, b: &Robot) -> bool {
+        a.mode != RobotMode::Stranded && b.mode != RobotMode::Stranded
+            && a.x.abs_diff(b.x) <= self.radio_range && a.y.abs_diff(b.y) <= self.radio_range
+    }
+
+    // Synchronise les robots à portée les uns des autres, avec la règle de résolution de la
+    // station pour que les robots ne retiennent pas ce qu'elle écarterait.
+    // Une rencontre dure tant que les deux robots restent à portée; son budget n'est
+    // rechargé qu'après qu'ils se sont séparés.
+    pub fn exchange(&mut self, robots: &mut [Robot], policy: ResolutionPolicy, stats: &mut KnowledgeStats) {
+        let mut still_in_range = Vec::new();
+
+        for i in 0..robots.len() {
+            let (left, right) = robots.split_at_mut(i + 1);
+            let a = &mut left[i];
+
+            for b in right.iter_mut() {
+                if !self.in_range(a, b) {
+                    continue;
+                }
+
+                // Les robots qui partagent la même station s'y synchronisent déjà
+                if a.is_at_station() && b.is_at_station() {
+                    continue;
+                }
+
+                let key = (a.id.min(b.id), a.id.max(b.id));
+                still_in_range.push(key);
+
+                let used = self.encounters.entry(key).or_insert_with(|| {
+                    stats.peer_encounters += 1;
+                    (0, 0)
+                });
+
+                // Le sens 0 va du plus petit id vers le plus grand
+                let (low, high) = if a.id <= b.id { (&mut *a, &mut *b) } else { (&mut *b, &mut *a) };
+
+                let sent = transfer(low, high, self.bandwidth.saturating_sub(used.0), policy);
+                used.0 += sent;
+                let received = transfer(high, low, self.bandwidth.saturating_sub(used.1), policy);
+                used.1 += received;
+
+                stats.tiles_via_peers += sent + received;
+            }
+        }
+
+        // Oublier les rencontres terminées
+        self.encounters.retain(|key, _| still_in_range.contains(key));
+    }
+}
+
+// Transmet au plus `budget` cases que le destinataire retiendrait selon la règle, les plus
+// récentes d'abord; le destinataire les transmettra à sa prochaine synchronisation.
+// Retourne le nombre de cases mises à jour chez lui.
+fn transfer(source: &Robot, destination: &mut Robot, budget: usize, policy: ResolutionPolicy) -> usize {
+    if budget == 0 {
+        return 0;
+    }
+
+    let mut stronger = Vec::new();
+    for y in 0..MAP_SIZE {
+        for x in 0..MAP_SIZE {
+            let tile = &source.memory[y][x];
+            let known = &destination.memory[y][x];
+            if tile.explored && (!known.explored || policy.prefers(known, tile)) {
+                stronger.push((tile.timestamp, x, y));
+            }
+        }
+    }
+
+    stronger.sort_by_key(|&(timestamp, _, _)| Reverse(timestamp));
+    stronger.truncate(budget);
+
+    let mut updated = 0;
+    for (_, x, y) in stronger {
+        if join(&mut destination.memory[y][x], &source.memory[y][x], policy) {
+            destination.changes.mark((x, y));
+            updated += 1;
+        }
+    }
+    updated
+}
@@ -1,7 +1,34 @@
 // src/lib.rs
+// Les grilles sont parcourues par indices (x, y) dans tout le projet
+#![allow(clippy::needless_range_loop)]
+
 pub mod types;
+pub mod movement;
 pub mod map;
+pub mod pathfinding;
+pub mod exploration;
+pub mod fov;
+pub mod sensors;
+pub mod behavior;
+pub mod scripting;
+pub mod events;
+pub mod knowledge;
+pub mod audit;
+pub mod crdt;
+pub mod comms;
+pub mod traffic;
+pub mod tasks;
+pub mod rescue;
+pub mod inventory;
+pub mod wear;
 pub mod robot;
 pub mod display;
+pub mod economy;
+pub mod construction;
+pub mod planner;
+pub mod research;
+pub mod outposts;
+pub mod docking;
 pub mod station;
-pub mod network;
\ No newline at end of file
+pub mod scenario;
+pub mod network;
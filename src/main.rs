@@ -1,24 +1,36 @@
-mod types;
-mod map;
-mod robot;
-mod display;
-mod station;
+// src/main.rs
+use ereea::types::{self, RobotType};
+use ereea::robot::Robot;
+use ereea::display::Display;
+use ereea::scripting::load_script_dir;
+use ereea::sensors::SensorModel;
+use ereea::scenario::Scenario;
 
 use std::{thread, time::Duration};
 use crossterm::terminal::{enable_raw_mode, disable_raw_mode};
-use types::{MAP_SIZE, RobotType};
-use map::Map;
-use robot::Robot;
-use display::Display;
-use station::Station;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Configuration du terminal
     enable_raw_mode()?;
     
     // Initialisation avec carte aléatoire
-    let mut map = Map::new();
-    let mut station = Station::new();
+    let scenario = load_scenario();
+    let mut map = scenario.map();
+    let mut station = scenario.station();
+    scenario.place_outposts(&mut station, &mut map);
+    
+    // Scripts de contrôle optionnels (<type>.rhai dans le dossier EREEA_SCRIPTS)
+    if let Ok(dir) = std::env::var("EREEA_SCRIPTS") {
+        let (_, errors) = load_script_dir(&mut station.behaviors, dir.as_ref());
+        for error in errors {
+            eprintln!("Erreur de script: {}", error);
+        }
+    }
+    
+    // Capteurs bruités optionnels (erreurs de classification, occultation par les obstacles)
+    if std::env::var("EREEA_NOISY_SENSORS").is_ok() {
+        station.sensors = Some(SensorModel::default());
+    }
     
     // Créer différents robots
     let mut robots = vec![
@@ -66,11 +78,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // S'assurer que tous les robots sont en mode exploration
     for robot in robots.iter_mut() {
         robot.mode = types::RobotMode::Exploring;
+        robot.set_behavior(station.behaviors.create(robot.robot_type));
     }
     
     // Boucle principale
     let mut iteration = 0;
-    let mut last_robot_creation = 0;
     
     loop {
         // Affichage
@@ -79,25 +91,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Incrémentation de l'horloge de la station
         station.tick();
         
+        // Entretien de la flotte prélevé sur les réserves
+        station.pay_upkeep(robots.len());
+        
         // Mise à jour des robots
+        station.update_traffic(&robots);
         for robot in robots.iter_mut() {
             robot.update(&mut map, &mut station);
-            
-            // Si le robot est à court d'énergie, le ramener à la station
-            if robot.energy <= 0.0 {
-                robot.x = robot.home_station_x;
-                robot.y = robot.home_station_y;
-                robot.energy = robot.max_energy / 2.0;
-                robot.mode = types::RobotMode::Idle;
-            }
         }
         
-        // Vérifier si la station peut créer un nouveau robot (tous les 50 cycles)
-        if iteration - last_robot_creation >= 50 {
-            if let Some(new_robot) = station.try_create_robot(&map) {
-                robots.push(new_robot);
-                last_robot_creation = iteration;
-            }
+        // Pannes d'énergie: secours ou abandon des robots immobilisés
+        station.handle_failures(&map, &mut robots);
+        
+        // Échanges radio entre robots proches
+        station.exchange_peer_knowledge(&mut robots);
+        
+        // Couverture radio et transmission en direct des observations
+        station.update_communications(&map, &mut robots);
+        
+        // Attribution des collectes aux enchères
+        station.allocate_tasks(&map, &mut robots);
+        
+        // Recherche financée par les données scientifiques
+        station.update_research(&mut robots);
+        
+        // Avant-postes: fondation, rattachement des robots et réplication
+        station.update_outposts(&mut map, &mut robots);
+        
+        // Avancer la file de construction (un robot à la fois)
+        if let Some(new_robot) = station.advance_construction(&map, &robots) {
+            robots.push(new_robot);
         }
         
         // Attendre
@@ -113,4 +136,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Restaurer le terminal
     disable_raw_mode()?;
     Ok(())
-}
\ No newline at end of file
+}
+
+// Scénario optionnel (fichier JSON indiqué par EREEA_SCENARIO): graine et économie de la station
+fn load_scenario() -> Scenario {
+    match std::env::var("EREEA_SCENARIO") {
+        Ok(path) => Scenario::load(path.as_ref()).unwrap_or_else(|error| {
+            eprintln!("Erreur de scénario: {}", error);
+            Scenario::default()
+        }),
+        Err(_) => Scenario::default(),
+    }
+}
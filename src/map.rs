@@ -1,24 +1,51 @@
 use crate::types::{TileType, MAP_SIZE};
+use crate::movement::MovementRules;
+use crate::pathfinding::Pathfinder;
 use noise::{NoiseFn, Perlin};
 use rand::prelude::*;
-use std::collections::{VecDeque, HashSet};
+use rand::rngs::StdRng;
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+
+// Position de la station principale sur les cartes générées
+pub const MAIN_STATION: (usize, usize) = (MAP_SIZE / 2, MAP_SIZE / 2);
 
 pub struct Map {
-    pub tiles: Vec<Vec<TileType>>,
+    tiles: Vec<Vec<TileType>>,   // Modifiées uniquement par `set_tile`
     pub station_x: usize,
     pub station_y: usize,
+    pub movement: MovementRules, // Règles de déplacement de la simulation
+    terrain_revision: u64,       // Incrémenté quand la praticabilité d'une case change
+    tiles_revision: u64,         // Incrémenté à chaque modification de tuile
+    pathfinder: RefCell<Pathfinder>, // Cache de recherche de chemin partagé par les robots
+    rng: StdRng,                 // Tracé des chemins creusés vers les ressources et avant-postes
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Map {
     pub fn new() -> Self {
+        Self::new_with_movement(MovementRules::default())
+    }
+    
+    // Génère une carte aléatoire avec des règles de déplacement spécifiques
+    pub fn new_with_movement(movement: MovementRules) -> Self {
         // Générer une seed aléatoire à chaque exécution
-        let seed: u32 = rand::thread_rng().gen();
-        let perlin = Perlin::new(seed);
+        Self::generate(movement, rand::thread_rng().gen())
+    }
+    
+    // Génère la carte d'une seed donnée: même seed, même monde
+    pub fn generate(movement: MovementRules, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let perlin = Perlin::new(rng.gen());
         let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
         
         // Station au centre
-        let station_x = MAP_SIZE / 2;
-        let station_y = MAP_SIZE / 2;
+        let (station_x, station_y) = MAIN_STATION;
         
         // Première passe: générer la carte avec du bruit de Perlin
         for y in 0..MAP_SIZE {
@@ -51,11 +78,8 @@ impl Map {
         }
         
         // Créer la carte
-        let mut map = Self {
-            tiles,
-            station_x,
-            station_y,
-        };
+        let mut map = Self::from_tiles(tiles, station_x, station_y, movement);
+        map.rng = rng;
         
         // Identifier toutes les ressources et assurer leur accessibilité
         let mut resources = Vec::new();
@@ -80,6 +104,25 @@ impl Map {
         map
     }
     
+    // Construit une carte à partir de tuiles existantes (scénarios, tests)
+    pub fn from_tiles(tiles: Vec<Vec<TileType>>, station_x: usize, station_y: usize, movement: MovementRules) -> Self {
+        Self {
+            tiles,
+            station_x,
+            station_y,
+            movement,
+            terrain_revision: 0,
+            tiles_revision: 0,
+            pathfinder: RefCell::new(Pathfinder::new()),
+            rng: StdRng::seed_from_u64(0),
+        }
+    }
+    
+    // Lecture de toute la grille (indexée [y][x])
+    pub fn tiles(&self) -> &[Vec<TileType>] {
+        &self.tiles
+    }
+    
     pub fn get_tile(&self, x: usize, y: usize) -> TileType {
         self.tiles[y][x].clone()
     }
@@ -88,16 +131,86 @@ impl Map {
         x < MAP_SIZE && y < MAP_SIZE && self.tiles[y][x] != TileType::Obstacle
     }
     
+    // Modifier une tuile en invalidant les caches de recherche de chemin.
+    // Toute modification de `tiles` après la création doit passer par ici.
+    pub fn set_tile(&mut self, x: usize, y: usize, tile: TileType) {
+        if self.tiles[y][x] == tile {
+            return;
+        }
+        
+        if (self.tiles[y][x] == TileType::Obstacle) != (tile == TileType::Obstacle) {
+            self.terrain_revision += 1;
+        }
+        self.tiles_revision += 1;
+        self.tiles[y][x] = tile;
+    }
+    
+    pub fn terrain_revision(&self) -> u64 {
+        self.terrain_revision
+    }
+    
+    pub fn tiles_revision(&self) -> u64 {
+        self.tiles_revision
+    }
+    
     // Consommer une ressource à une position
     pub fn consume_resource(&mut self, x: usize, y: usize) {
         match self.tiles[y][x] {
             TileType::Energy | TileType::Mineral | TileType::Scientific => {
-                self.tiles[y][x] = TileType::Empty;
+                self.set_tile(x, y, TileType::Empty);
             },
             _ => {}
         }
     }
     
+    // Chemin optimal (A*) entre deux cases
+    pub fn find_path(&self, start: (usize, usize), target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.pathfinder.borrow_mut().find_path(self, start, target)
+    }
+    
+    // Chemin optimal (A*) qui contourne des cases occupées ou réservées
+    pub fn find_path_avoiding(&self, start: (usize, usize), target: (usize, usize),
+                              avoid: &HashSet<(usize, usize)>) -> VecDeque<(usize, usize)> {
+        self.pathfinder.borrow_mut().find_path_avoiding(self, start, target, avoid)
+    }
+    
+    // Chemin vers une destination fixe (station) à partir du champ de distance en cache
+    pub fn path_to_goal(&self, start: (usize, usize), goal: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.pathfinder.borrow_mut().goal_field(self, goal).path_from(start)
+    }
+    
+    // Coût du trajet vers une destination fixe, en unités de `STEP_COST`
+    pub fn travel_cost_to_goal(&self, start: (usize, usize), goal: (usize, usize)) -> Option<usize> {
+        self.pathfinder.borrow_mut().goal_field(self, goal).cost(start)
+    }
+    
+    // Chemin vers la ressource d'un type la plus proche (en coût de trajet réel)
+    pub fn path_to_nearest_resource(&self, start: (usize, usize), resource: TileType) -> Option<VecDeque<(usize, usize)>> {
+        let mut pathfinder = self.pathfinder.borrow_mut();
+        let field = pathfinder.resource_field(self, resource);
+        field.cost(start)?;
+        Some(field.path_from(start))
+    }
+    
+    // Position et coût de trajet de la ressource d'un type la plus proche
+    pub fn nearest_resource(&self, start: (usize, usize), resource: TileType) -> Option<((usize, usize), usize)> {
+        let mut pathfinder = self.pathfinder.borrow_mut();
+        let field = pathfinder.resource_field(self, resource);
+        let cost = field.cost(start)?;
+        let target = field.path_from(start).back().copied().unwrap_or(start);
+        Some((target, cost))
+    }
+    
+    // Rend une case praticable et accessible depuis la station (avant-postes placés par scénario)
+    pub fn connect_to_station(&mut self, x: usize, y: usize) {
+        if self.tiles[y][x] == TileType::Obstacle {
+            self.set_tile(x, y, TileType::Empty);
+        }
+        if !self.is_accessible(self.station_x, self.station_y, x, y) {
+            self.create_path(self.station_x, self.station_y, x, y);
+        }
+    }
+    
     // Vérifie si une position est accessible depuis une autre (BFS)
     fn is_accessible(&self, start_x: usize, start_y: usize, target_x: usize, target_y: usize) -> bool {
         let mut visited = vec![vec![false; MAP_SIZE]; MAP_SIZE];
@@ -113,25 +226,11 @@ impl Map {
                 return true;
             }
             
-            // Explorer les voisins
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    if dx == 0 && dy == 0 {
-                        continue;
-                    }
-                    
-                    let nx = x as isize + dx;
-                    let ny = y as isize + dy;
-                    
-                    if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize {
-                        let nx = nx as usize;
-                        let ny = ny as usize;
-                        
-                        if !visited[ny][nx] && self.tiles[ny][nx] != TileType::Obstacle {
-                            visited[ny][nx] = true;
-                            queue.push_back((nx, ny));
-                        }
-                    }
+            // Explorer les voisins selon les règles de déplacement
+            for (nx, ny) in self.movement.neighbors((x, y), |nx, ny| self.is_valid_position(nx, ny)) {
+                if !visited[ny][nx] {
+                    visited[ny][nx] = true;
+                    queue.push_back((nx, ny));
                 }
             }
         }
@@ -147,7 +246,7 @@ impl Map {
         
         while current_x != target_x || current_y != target_y {
             // Décider de la direction à prendre
-            let move_horizontal = rand::thread_rng().gen_bool(0.5);
+            let move_horizontal = self.rng.gen_bool(0.5);
             
             if move_horizontal && current_x != target_x {
                 // Déplacement horizontal
@@ -174,7 +273,7 @@ impl Map {
             
             // Si c'est un obstacle, le transformer en case vide
             if self.tiles[current_y][current_x] == TileType::Obstacle {
-                self.tiles[current_y][current_x] = TileType::Empty;
+                self.set_tile(current_x, current_y, TileType::Empty);
             }
         }
     }
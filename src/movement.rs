@@ -0,0 +1,124 @@
+use serde::{Serialize, Deserialize};
+use crate::types::MAP_SIZE;
+
+// Coût d'un pas orthogonal (les coûts sont entiers et mis à l'échelle par 10)
+pub const STEP_COST: usize = 10;
+// Coût d'un pas diagonal pour la métrique octile (≈ 10 × √2)
+pub const DIAGONAL_STEP_COST: usize = 14;
+
+// Métrique de distance utilisée pour l'heuristique et le coût des déplacements
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    Manhattan,  // Un pas diagonal compte pour deux pas orthogonaux
+    Chebyshev,  // Un pas diagonal coûte autant qu'un pas orthogonal
+    Octile,     // Un pas diagonal coûte √2 pas orthogonal
+}
+
+// Voisinage autorisé pour les déplacements
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Connectivity {
+    Four,   // Déplacements orthogonaux uniquement
+    Eight,  // Déplacements orthogonaux et diagonaux
+}
+
+// Règles de déplacement d'une simulation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MovementRules {
+    pub metric: DistanceMetric,
+    pub connectivity: Connectivity,
+    pub corner_cutting: bool,   // Autoriser une diagonale qui frôle un obstacle
+}
+
+impl Default for MovementRules {
+    // Règles historiques: 8 voisins, diagonale au même prix qu'un pas orthogonal
+    fn default() -> Self {
+        Self {
+            metric: DistanceMetric::Chebyshev,
+            connectivity: Connectivity::Eight,
+            corner_cutting: true,
+        }
+    }
+}
+
+impl DistanceMetric {
+    // Distance entre deux cases, exprimée en unités de coût (STEP_COST par case)
+    pub fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        let dx = a.0.abs_diff(b.0);
+        let dy = a.1.abs_diff(b.1);
+
+        match self {
+            DistanceMetric::Manhattan => (dx + dy) * STEP_COST,
+            DistanceMetric::Chebyshev => dx.max(dy) * STEP_COST,
+            DistanceMetric::Octile => {
+                let (min, max) = (dx.min(dy), dx.max(dy));
+                min * DIAGONAL_STEP_COST + (max - min) * STEP_COST
+            }
+        }
+    }
+}
+
+impl MovementRules {
+    pub fn new(metric: DistanceMetric, connectivity: Connectivity, corner_cutting: bool) -> Self {
+        Self { metric, connectivity, corner_cutting }
+    }
+
+    // Heuristique admissible et cohérente: le coût d'un pas est toujours
+    // égal à la distance de la métrique entre les deux cases
+    pub fn heuristic(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        self.metric.distance(a, b)
+    }
+
+    // Coût d'un pas entre deux cases voisines
+    pub fn step_cost(&self, from: (usize, usize), to: (usize, usize)) -> usize {
+        self.metric.distance(from, to)
+    }
+
+    // Distance exprimée en nombre de cases (arrondie à l'inférieur)
+    pub fn tile_distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        self.metric.distance(a, b) / STEP_COST
+    }
+
+    // Liste des voisins accessibles d'une case selon la connectivité et la règle des coins.
+    // `passable` indique si une case peut être traversée.
+    pub fn neighbors<F>(&self, pos: (usize, usize), passable: F) -> Vec<(usize, usize)>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let mut neighbors = Vec::with_capacity(8);
+
+        for dy in -1..=1isize {
+            for dx in -1..=1isize {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+
+                let diagonal = dx != 0 && dy != 0;
+                if diagonal && self.connectivity == Connectivity::Four {
+                    continue;
+                }
+
+                let nx = pos.0 as isize + dx;
+                let ny = pos.1 as isize + dy;
+
+                if nx < 0 || nx >= MAP_SIZE as isize || ny < 0 || ny >= MAP_SIZE as isize {
+                    continue;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+                if !passable(nx, ny) {
+                    continue;
+                }
+
+                // Sans coupe de coin, les deux cases orthogonales doivent être libres
+                if diagonal && !self.corner_cutting
+                    && (!passable(nx, pos.1) || !passable(pos.0, ny)) {
+                    continue;
+                }
+
+                neighbors.push((nx, ny));
+            }
+        }
+
+        neighbors
+    }
+}
@@ -1,6 +1,18 @@
 // src/network/mod.rs
 use serde::{Serialize, Deserialize};
 use crate::types::{MAP_SIZE, TileType, RobotType, RobotMode};
+use crate::events::EventRecord;
+use crate::knowledge::KnowledgeStats;
+use crate::traffic::TrafficStats;
+use crate::tasks::MarketStats;
+use crate::rescue::RescueStats;
+use crate::inventory::Inventory;
+use crate::wear::{Health, WearStats};
+use crate::construction::{BuildOrder, BuildOrigin};
+use crate::planner::BuildDecision;
+use crate::research::{Research, Technology};
+use crate::docking::DockingStats;
+use crate::audit::{ConflictQuery, ConflictRecord, ResolutionPolicy};
 
 // Structure pour représenter les données sur la carte
 #[derive(Serialize, Deserialize, Clone)]
@@ -18,11 +30,26 @@ pub struct RobotData {
     pub y: usize,
     pub energy: f32,
     pub max_energy: f32,
-    pub minerals: u32,
-    pub scientific_data: u32,
+    pub inventory: Inventory,   // Soute: emplacements, capacités et contenu
     pub robot_type: RobotType,
     pub mode: RobotMode,
     pub exploration_percentage: f32,
+    pub in_contact: bool,   // À portée radio de la station
+    pub task: Option<(usize, usize)>, // Gisement attribué par le marché des tâches
+    pub health: Health,     // Usure des moteurs, des capteurs et de la batterie
+}
+
+// Structure pour représenter un avant-poste
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OutpostData {
+    pub id: usize,
+    pub x: usize,
+    pub y: usize,
+    pub energy_reserves: u32,
+    pub collected_minerals: u32,
+    pub collected_scientific_data: u32,
+    pub exploration_percentage: f32,    // Part de la carte connue de l'avant-poste
+    pub founder: Option<usize>,
 }
 
 // Structure pour représenter les données de la station
@@ -35,6 +62,21 @@ pub struct StationData {
     pub conflict_count: usize,
     pub robot_count: usize,
     pub status_message: String,
+    pub knowledge_stats: KnowledgeStats, // Cases propagées via la station / entre robots
+    pub traffic_stats: TrafficStats,     // Attentes, détours et interblocages résolus
+    pub open_tasks: usize,               // Contrats de collecte publiés et non attribués
+    pub market_stats: MarketStats,       // Activité du marché des tâches
+    pub rescue_stats: RescueStats,       // Pannes, secours, recharges sur le terrain et robots perdus
+    pub wear_stats: WearStats,           // Défaillances, réparations et batteries remplacées
+    pub build_queue: Vec<BuildOrder>,    // Robots commandés, dans l'ordre de construction
+    pub build_plan: Option<BuildDecision>, // Dernier choix du planificateur de flotte et sa justification
+    pub research: Research,              // Niveaux acquis et projet de recherche en cours
+    pub outposts: Vec<OutpostData>,      // Avant-postes et leurs réserves
+    pub docking: String,                 // Occupation des baies et files d'attente des quais
+    pub docking_stats: DockingStats,     // Attentes aux quais et énergie délivrée aux robots
+    pub merge_policy: ResolutionPolicy,  // Règle de résolution des désaccords entre observations
+    pub conflict_query: ConflictQuery,   // Requête de la Terre sur le journal d'audit
+    pub conflicts: Vec<ConflictRecord>,  // Conflits du journal qui y répondent
 }
 
 // Structure pour représenter les données d'exploration
@@ -50,19 +92,33 @@ pub struct SimulationState {
     pub robots_data: Vec<RobotData>,
     pub station_data: StationData,
     pub exploration_data: ExplorationData,
+    pub events: Vec<EventRecord>,   // Derniers événements de la station
     pub iteration: u32,
 }
 
+// Commandes envoyées par la Terre à la simulation (une ligne JSON par commande)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum EarthCommand {
+    Build(RobotType),       // Commander un robot
+    CancelBuild(usize),     // Annuler une commande de la file de construction
+    Research(Technology),   // Choisir le projet de recherche en cours
+    MergePolicy(ResolutionPolicy), // Changer la règle de résolution des désaccords
+    QueryConflicts(ConflictQuery), // Choisir les conflits du journal d'audit transmis
+}
+
 // Port pour la communication TCP
 pub const DEFAULT_PORT: u16 = 8081;
 
+// Nombre d'événements transmis à chaque état
+pub const EVENTS_PER_STATE: usize = 10;
+
 // Constantes pour la taille des messages
 pub const MAX_MESSAGE_SIZE: usize = 1024 * 1024; // 1 MB
 
 // Fonction utilitaire pour créer une structure MapData à partir de Map
 pub fn create_map_data(map: &crate::map::Map) -> MapData {
     MapData {
-        tiles: map.tiles.clone(),
+        tiles: map.tiles().to_vec(),
         station_x: map.station_x,
         station_y: map.station_y,
     }
@@ -76,24 +132,84 @@ pub fn create_robot_data(robot: &crate::robot::Robot) -> RobotData {
         y: robot.y,
         energy: robot.energy,
         max_energy: robot.max_energy,
-        minerals: robot.minerals,
-        scientific_data: robot.scientific_data,
+        inventory: robot.inventory.clone(),
         robot_type: robot.robot_type,
         mode: robot.mode,
         exploration_percentage: robot.get_exploration_percentage(),
+        in_contact: robot.in_contact,
+        task: robot.task,
+        health: robot.health.clone(),
+    }
+}
+
+// Fonction utilitaire pour créer une structure OutpostData à partir d'un Outpost
+pub fn create_outpost_data(outpost: &crate::outposts::Outpost) -> OutpostData {
+    let explored = outpost.memory.iter().flatten().filter(|t| t.explored).count();
+    OutpostData {
+        id: outpost.id,
+        x: outpost.position.0,
+        y: outpost.position.1,
+        energy_reserves: outpost.energy_reserves,
+        collected_minerals: outpost.collected_minerals,
+        collected_scientific_data: outpost.collected_scientific_data,
+        exploration_percentage: explored as f32 / (MAP_SIZE * MAP_SIZE) as f32 * 100.0,
+        founder: outpost.founder,
     }
 }
 
 // Fonction utilitaire pour créer une structure StationData à partir de Station
-pub fn create_station_data(station: &crate::station::Station) -> StationData {
+pub fn create_station_data(station: &crate::station::Station, robot_count: usize) -> StationData {
     StationData {
         energy_reserves: station.energy_reserves,
         collected_minerals: station.collected_minerals,
         collected_scientific_data: station.collected_scientific_data,
         exploration_percentage: station.get_exploration_percentage(),
         conflict_count: station.conflict_count,
-        robot_count: station.next_robot_id - 1, // Estimation du nombre de robots
+        robot_count, // Flotte en service (les robots abandonnés n'y sont plus)
         status_message: station.get_status(),
+        knowledge_stats: station.knowledge_stats.clone(),
+        traffic_stats: station.traffic.stats.clone(),
+        open_tasks: station.market.tasks().iter().filter(|t| t.assignee.is_none()).count(),
+        market_stats: station.market.stats.clone(),
+        rescue_stats: station.rescue.stats.clone(),
+        wear_stats: station.maintenance.stats.clone(),
+        build_queue: station.construction.orders(),
+        build_plan: station.last_plan.clone(),
+        research: station.research.clone(),
+        outposts: station.outposts.sites.iter().map(create_outpost_data).collect(),
+        docking: station.docking.summary(station.economy.docking_bays),
+        docking_stats: station.docking.stats.clone(),
+        merge_policy: station.merge_policy,
+        conflict_query: station.conflict_query.clone(),
+        conflicts: station.audit.query(&station.conflict_query),
+    }
+}
+
+// Fonction utilitaire pour appliquer une commande de la Terre à la station
+pub fn apply_earth_command(station: &mut crate::station::Station, command: EarthCommand) {
+    match command {
+        EarthCommand::Build(robot_type) => {
+            let id = station.enqueue_build(robot_type, BuildOrigin::Earth);
+            println!("Terre: commande #{} d'un robot {:?}", id, robot_type);
+        }
+        EarthCommand::CancelBuild(id) => {
+            if station.cancel_build(id) {
+                println!("Terre: commande #{} annulée", id);
+            }
+        }
+        EarthCommand::Research(technology) => {
+            if station.research.select(technology) {
+                println!("Terre: recherche en {}", technology);
+            }
+        }
+        EarthCommand::MergePolicy(policy) => {
+            station.set_merge_policy(policy);
+            println!("Terre: désaccords tranchés par la règle '{}'", policy);
+        }
+        EarthCommand::QueryConflicts(query) => {
+            println!("Terre: conflits demandés ({})", query);
+            station.conflict_query = query;
+        }
     }
 }
 
@@ -122,7 +238,7 @@ pub fn create_simulation_state(map: &crate::map::Map, station: &crate::station::
         robots_data.push(create_robot_data(robot));
     }
     
-    let station_data = create_station_data(station);
+    let station_data = create_station_data(station, robots.len());
     let exploration_data = create_exploration_data(station);
     
     SimulationState {
@@ -130,6 +246,7 @@ pub fn create_simulation_state(map: &crate::map::Map, station: &crate::station::
         robots_data,
         station_data,
         exploration_data,
+        events: station.events.recent(EVENTS_PER_STATE),
         iteration,
     }
 }
\ No newline at end of file
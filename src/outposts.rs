@@ -0,0 +1,137 @@
+// src/outposts.rs
+// Avant-postes: stations secondaires placées par le scénario ou fondées sur le terrain par un
+// collecteur de minerais avec le contenu de sa soute. Chaque avant-poste a son quai, ses réserves
+// et sa propre copie des connaissances; les robots se rattachent à la station la plus proche qui
+// a de quoi les recharger.
+// La station principale reste seule à planifier et construire: à intervalles réguliers, les
+// connaissances sont répliquées entre les stations (par deltas, voir crdt) et les minerais et
+// données scientifiques des avant-postes lui sont transférés. Chaque avant-poste garde son
+// énergie pour recharger les robots à son quai.
+use crate::types::{RobotType, RobotMode, TileType};
+use crate::map::Map;
+use crate::movement::STEP_COST;
+use crate::robot::Robot;
+use crate::station::TerrainData;
+use crate::inventory::CargoKind;
+use crate::audit::ResolutionPolicy;
+use crate::crdt::{resync, sync, Replica};
+
+// Nombre maximal d'avant-postes
+pub const MAX_OUTPOSTS: usize = 2;
+
+// Minerais consommés pour fonder un avant-poste (soute pleine d'un collecteur de minerais)
+pub const OUTPOST_MINERALS: u32 = 5;
+
+// Trajet minimal (en cases) entre un nouvel avant-poste et la station la plus proche
+pub const OUTPOST_MIN_DISTANCE: usize = 8;
+
+// Tours entre deux réplications des connaissances et transferts de réserves
+pub const REPLICATION_INTERVAL: u32 = 20;
+
+pub struct Outpost {
+    pub id: usize,
+    pub position: (usize, usize),
+    pub energy_reserves: u32,
+    pub collected_minerals: u32,
+    pub collected_scientific_data: u32,
+    pub memory: Vec<Vec<TerrainData>>,  // Connaissances de l'avant-poste
+    pub replica: Replica,               // Réplication de ces connaissances avec les autres stations
+    pub founder: Option<usize>,         // Robot fondateur (aucun si placé par le scénario)
+}
+
+// Réserves transférées et cases répliquées lors d'une réplication
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Replication {
+    pub minerals: u32,
+    pub scientific_data: u32,
+    pub tiles: usize,
+}
+
+#[derive(Default)]
+pub struct Outposts {
+    pub sites: Vec<Outpost>,
+}
+
+impl Outposts {
+    pub fn found(&mut self, position: (usize, usize), memory: Vec<Vec<TerrainData>>, main: &Replica,
+                 founder: Option<usize>) -> usize {
+        let id = self.sites.len() + 1; // La station principale porte le numéro 0
+        self.sites.push(Outpost {
+            id,
+            position,
+            energy_reserves: 0,
+            collected_minerals: 0,
+            collected_scientific_data: 0,
+            memory,
+            replica: main.fork(id),
+            founder,
+        });
+        id
+    }
+
+    pub fn at(&self, position: (usize, usize)) -> Option<&Outpost> {
+        self.sites.iter().find(|o| o.position == position)
+    }
+
+    pub fn at_mut(&mut self, position: (usize, usize)) -> Option<&mut Outpost> {
+        self.sites.iter_mut().find(|o| o.position == position)
+    }
+
+    // Quai le plus proche en coût de trajet, station principale comprise
+    pub fn nearest_dock(&self, map: &Map, from: (usize, usize), main: (usize, usize)) -> Option<((usize, usize), usize)> {
+        self.nearest_supplied_dock(map, from, main, 0)
+    }
+
+    // Quai le plus proche parmi la station principale et les avant-postes qui ont au moins
+    // `min_energy` en réserve pour recharger
+    pub fn nearest_supplied_dock(&self, map: &Map, from: (usize, usize), main: (usize, usize),
+                                 min_energy: u32) -> Option<((usize, usize), usize)> {
+        std::iter::once(main)
+            .chain(self.sites.iter().filter(|o| o.energy_reserves >= min_energy).map(|o| o.position))
+            .filter_map(|dock| map.travel_cost_to_goal(from, dock).map(|cost| (dock, cost)))
+            .min_by_key(|(_, cost)| *cost)
+    }
+
+    // Collecteur de minerais à la soute pleine, loin de toute station, sur une case libre:
+    // il peut fonder un avant-poste à sa position
+    pub fn can_found(&self, map: &Map, robot: &Robot, main: (usize, usize)) -> bool {
+        if self.sites.len() >= MAX_OUTPOSTS
+            || robot.robot_type != RobotType::MineralCollector
+            || robot.mode == RobotMode::Stranded
+            || robot.rescue.is_some()
+            || robot.inventory.amount(CargoKind::Mineral) < OUTPOST_MINERALS
+            || map.get_tile(robot.x, robot.y) != TileType::Empty {
+            return false;
+        }
+        match self.nearest_dock(map, (robot.x, robot.y), main) {
+            Some((_, cost)) => cost >= OUTPOST_MIN_DISTANCE * STEP_COST,
+            None => false,
+        }
+    }
+
+    // Synchronise les connaissances de chaque avant-poste avec celles de la station principale,
+    // dans les deux sens et par deltas, et transfère minerais et données scientifiques
+    pub fn replicate(&mut self, main_memory: &mut [Vec<TerrainData>], main: &mut Replica,
+                     policy: ResolutionPolicy) -> Replication {
+        let mut replication = Replication::default();
+        for outpost in self.sites.iter_mut() {
+            replication.tiles += sync(main_memory, main, &mut outpost.memory, &mut outpost.replica, policy);
+            replication.minerals += std::mem::take(&mut outpost.collected_minerals);
+            replication.scientific_data += std::mem::take(&mut outpost.collected_scientific_data);
+        }
+        replication
+    }
+
+    // Réévalue toutes les cases de la station principale et des avant-postes selon une nouvelle
+    // règle: un premier passage réunit tout à la station principale, le second le redistribue.
+    // Retourne le nombre de cases modifiées.
+    pub fn resync(&mut self, main_memory: &mut [Vec<TerrainData>], main: &mut Replica, policy: ResolutionPolicy) -> usize {
+        let mut updated = 0;
+        for _ in 0..2 {
+            for outpost in self.sites.iter_mut() {
+                updated += resync(main_memory, main, &mut outpost.memory, &mut outpost.replica, policy);
+            }
+        }
+        updated
+    }
+}
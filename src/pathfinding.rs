@@ -0,0 +1,275 @@
+use crate::types::{TileType, MAP_SIZE};
+use crate::map::Map;
+use std::collections::{VecDeque, BinaryHeap, HashMap, HashSet};
+use std::cmp::Ordering;
+
+// Valeur utilisée pour les cases inaccessibles dans les champs de distance
+pub const UNREACHABLE: usize = usize::MAX;
+
+// Index d'une case dans les tableaux plats de la grille
+fn index(pos: (usize, usize)) -> usize {
+    pos.1 * MAP_SIZE + pos.0
+}
+
+fn position(index: usize) -> (usize, usize) {
+    (index % MAP_SIZE, index / MAP_SIZE)
+}
+
+// Structure de nœud pour la file de priorité (A* et Dijkstra)
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Node {
+    position: (usize, usize),
+    g_cost: usize,  // Coût depuis le départ
+    f_cost: usize,  // Coût total estimé (g_cost + heuristique)
+}
+
+// Implémentation pour le tri dans la file de priorité
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // On inverse pour avoir une min-heap au lieu d'une max-heap
+        other.f_cost.cmp(&self.f_cost)
+            .then_with(|| self.g_cost.cmp(&other.g_cost))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// A* sur la grille avec des tampons réutilisés d'une recherche à l'autre
+pub struct GridSearch {
+    g_score: Vec<usize>,
+    came_from: Vec<usize>,
+    visited_at: Vec<u32>,   // Génération de la recherche qui a touché la case
+    generation: u32,
+    open_set: BinaryHeap<Node>,
+}
+
+impl Default for GridSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridSearch {
+    pub fn new() -> Self {
+        Self {
+            g_score: vec![UNREACHABLE; MAP_SIZE * MAP_SIZE],
+            came_from: vec![0; MAP_SIZE * MAP_SIZE],
+            visited_at: vec![0; MAP_SIZE * MAP_SIZE],
+            generation: 0,
+            open_set: BinaryHeap::new(),
+        }
+    }
+
+    // Coût connu d'une case pour la recherche en cours
+    fn score(&self, i: usize) -> usize {
+        if self.visited_at[i] == self.generation { self.g_score[i] } else { UNREACHABLE }
+    }
+
+    // Algorithme A* pour trouver le chemin optimal selon les règles de déplacement de la carte
+    pub fn find_path(&mut self, map: &Map, start: (usize, usize), target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.find_path_avoiding(map, start, target, &HashSet::new())
+    }
+
+    // A* en traitant des cases supplémentaires comme infranchissables (la cible reste permise)
+    pub fn find_path_avoiding(&mut self, map: &Map, start: (usize, usize), target: (usize, usize),
+                              avoid: &HashSet<(usize, usize)>) -> VecDeque<(usize, usize)> {
+        // Si déjà à destination
+        if start == target {
+            return VecDeque::new();
+        }
+
+        // Nouvelle génération: invalide les scores précédents sans réallouer
+        self.generation = self.generation.wrapping_add(1);
+        if self.generation == 0 {
+            self.visited_at.iter_mut().for_each(|v| *v = 0);
+            self.generation = 1;
+        }
+        self.open_set.clear();
+
+        let start_index = index(start);
+        self.g_score[start_index] = 0;
+        self.visited_at[start_index] = self.generation;
+        self.open_set.push(Node {
+            position: start,
+            g_cost: 0,
+            f_cost: map.movement.heuristic(start, target),
+        });
+
+        while let Some(current) = self.open_set.pop() {
+            let current_pos = current.position;
+
+            // Si on est arrivé à destination, reconstruire le chemin
+            if current_pos == target {
+                let mut path = VecDeque::new();
+                let mut current = index(target);
+
+                while current != start_index {
+                    path.push_front(position(current));
+                    current = self.came_from[current];
+                }
+
+                return path;
+            }
+
+            // Ignorer les entrées obsolètes de la file de priorité
+            if current.g_cost > self.score(index(current_pos)) {
+                continue;
+            }
+
+            let passable = |nx: usize, ny: usize| {
+                map.is_valid_position(nx, ny) && ((nx, ny) == target || !avoid.contains(&(nx, ny)))
+            };
+
+            for neighbor in map.movement.neighbors(current_pos, passable) {
+                let tentative_g_score = current.g_cost + map.movement.step_cost(current_pos, neighbor);
+                let neighbor_index = index(neighbor);
+
+                if tentative_g_score < self.score(neighbor_index) {
+                    self.came_from[neighbor_index] = index(current_pos);
+                    self.g_score[neighbor_index] = tentative_g_score;
+                    self.visited_at[neighbor_index] = self.generation;
+
+                    self.open_set.push(Node {
+                        position: neighbor,
+                        g_cost: tentative_g_score,
+                        f_cost: tentative_g_score + map.movement.heuristic(neighbor, target),
+                    });
+                }
+            }
+        }
+
+        // Si on ne trouve pas de chemin, retourner un chemin vide
+        VecDeque::new()
+    }
+}
+
+// Champ de distance (carte de Dijkstra) vers un ensemble de cases sources.
+// Chaque case connaît son coût jusqu'à la source la plus proche et le pas à faire pour s'en rapprocher.
+pub struct DistanceField {
+    costs: Vec<usize>,
+    next: Vec<Option<(usize, usize)>>,
+}
+
+impl DistanceField {
+    // Calcule le champ par un Dijkstra multi-sources
+    pub fn compute(map: &Map, sources: &[(usize, usize)]) -> Self {
+        let mut costs = vec![UNREACHABLE; MAP_SIZE * MAP_SIZE];
+        let mut next = vec![None; MAP_SIZE * MAP_SIZE];
+        let mut open_set = BinaryHeap::new();
+
+        for &source in sources {
+            costs[index(source)] = 0;
+            open_set.push(Node { position: source, g_cost: 0, f_cost: 0 });
+        }
+
+        while let Some(current) = open_set.pop() {
+            if current.g_cost > costs[index(current.position)] {
+                continue;
+            }
+
+            // Les règles de déplacement sont symétriques: on propage depuis les sources
+            for neighbor in map.movement.neighbors(current.position, |nx, ny| map.is_valid_position(nx, ny)) {
+                let cost = current.g_cost + map.movement.step_cost(neighbor, current.position);
+                let neighbor_index = index(neighbor);
+
+                if cost < costs[neighbor_index] {
+                    costs[neighbor_index] = cost;
+                    next[neighbor_index] = Some(current.position);
+                    open_set.push(Node { position: neighbor, g_cost: cost, f_cost: cost });
+                }
+            }
+        }
+
+        Self { costs, next }
+    }
+
+    // Coût jusqu'à la source la plus proche (None si inaccessible)
+    pub fn cost(&self, pos: (usize, usize)) -> Option<usize> {
+        match self.costs[index(pos)] {
+            UNREACHABLE => None,
+            cost => Some(cost),
+        }
+    }
+
+    // Prochain pas à faire depuis une case pour descendre le champ
+    pub fn next_step(&self, pos: (usize, usize)) -> Option<(usize, usize)> {
+        self.next[index(pos)]
+    }
+
+    // Chemin complet depuis une case jusqu'à la source la plus proche
+    pub fn path_from(&self, start: (usize, usize)) -> VecDeque<(usize, usize)> {
+        let mut path = VecDeque::new();
+        let mut current = start;
+
+        while let Some(step) = self.next_step(current) {
+            path.push_back(step);
+            current = step;
+        }
+
+        path
+    }
+}
+
+// Service de recherche de chemin partagé par tous les robots d'une carte.
+// Les champs de distance sont mis en cache et recalculés uniquement quand les tuiles changent.
+#[derive(Default)]
+pub struct Pathfinder {
+    search: GridSearch,
+    goal_fields: HashMap<(usize, usize), DistanceField>,
+    goal_fields_revision: u64,
+    resource_fields: HashMap<TileType, DistanceField>,
+    resource_fields_revision: u64,
+}
+
+impl Pathfinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A* ponctuel entre deux cases
+    pub fn find_path(&mut self, map: &Map, start: (usize, usize), target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.search.find_path(map, start, target)
+    }
+
+    // A* ponctuel en évitant certaines cases (robots, réservations)
+    pub fn find_path_avoiding(&mut self, map: &Map, start: (usize, usize), target: (usize, usize),
+                              avoid: &HashSet<(usize, usize)>) -> VecDeque<(usize, usize)> {
+        self.search.find_path_avoiding(map, start, target, avoid)
+    }
+
+    // Champ de distance vers une destination fixe (station), invalidé si le terrain change
+    pub fn goal_field(&mut self, map: &Map, goal: (usize, usize)) -> &DistanceField {
+        if self.goal_fields_revision != map.terrain_revision() {
+            self.goal_fields.clear();
+            self.goal_fields_revision = map.terrain_revision();
+        }
+
+        self.goal_fields
+            .entry(goal)
+            .or_insert_with(|| DistanceField::compute(map, &[goal]))
+    }
+
+    // Carte de Dijkstra vers toutes les ressources d'un type, invalidée dès qu'une tuile change
+    pub fn resource_field(&mut self, map: &Map, resource: TileType) -> &DistanceField {
+        if self.resource_fields_revision != map.tiles_revision() {
+            self.resource_fields.clear();
+            self.resource_fields_revision = map.tiles_revision();
+        }
+
+        self.resource_fields.entry(resource.clone()).or_insert_with(|| {
+            let mut sources = Vec::new();
+            for y in 0..MAP_SIZE {
+                for x in 0..MAP_SIZE {
+                    if map.tiles()[y][x] == resource {
+                        sources.push((x, y));
+                    }
+                }
+            }
+            DistanceField::compute(map, &sources)
+        })
+    }
+}
@@ -0,0 +1,141 @@
+// src/planner.rs
+// Planification de la flotte: choix du prochain robot à construire. Le planificateur ne connaît
+// de la planète que la mémoire globale de la station; il tient compte de la composition de la
+// flotte, de la part de carte inexplorée et de la tendance des réserves. La politique est
+// interchangeable via le trait `FleetPlanner`.
+use crate::types::{TileType, RobotType, MAP_SIZE};
+use crate::station::TerrainData;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+// Nombre maximal de relais radio construits par la station
+pub const MAX_RELAYS: usize = 2;
+
+// Poids de la part de carte inexplorée dans le score des explorateurs
+pub const EXPLORATION_WEIGHT: f32 = 8.0;
+
+// Au-delà de ce nombre de gisements connus, un collecteur de plus n'est pas plus utile
+pub const DEPOSIT_SATURATION: usize = 6;
+
+// Multiplicateur du besoin en collecteurs quand la réserve correspondante stagne ou baisse
+pub const SHORTAGE_FACTOR: f32 = 2.0;
+
+// Ce que la station sait au moment de choisir
+pub struct FleetView<'a> {
+    pub knowledge: &'a [Vec<TerrainData>],      // Mémoire globale de la station
+    pub composition: HashMap<RobotType, usize>, // Robots en service par type
+    pub unexplored: f32,                        // Part de la carte inexplorée (0 à 1)
+    pub energy_reserves: u32,
+    pub collected_minerals: u32,
+    pub energy_trend: f32,                      // Variation moyenne par tour
+    pub mineral_trend: f32,
+    pub out_of_contact: usize,                  // Robots hors de portée radio
+    pub relay_count: usize,
+    pub needs_repairer: bool,                   // Robots usés sans réparateur disponible
+}
+
+impl FleetView<'_> {
+    pub fn count(&self, robot_type: RobotType) -> usize {
+        self.composition.get(&robot_type).copied().unwrap_or(0)
+    }
+
+    // Gisements d'une ressource connus de la station
+    pub fn known_deposits(&self, tile: TileType) -> usize {
+        let mut count = 0;
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                let data = &self.knowledge[y][x];
+                if data.explored && data.tile == tile {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+// Choix du planificateur et sa justification, transmis à la Terre
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuildDecision {
+    pub robot_type: RobotType,
+    pub rationale: String,
+}
+
+impl std::fmt::Display for BuildDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} ({})", self.robot_type, self.rationale)
+    }
+}
+
+pub trait FleetPlanner: Send {
+    fn plan(&self, view: &FleetView) -> BuildDecision;
+}
+
+// Politique par défaut: besoins urgents (radio, réparations) d'abord, puis le type dont le
+// besoin par robot déjà en service est le plus grand
+#[derive(Clone, Debug, Default)]
+pub struct BalancedPlanner;
+
+impl BalancedPlanner {
+    // Besoin en collecteurs d'une ressource: gisements connus par collecteur, doublé en cas de pénurie
+    fn collector_need(view: &FleetView, robot_type: RobotType, tile: TileType, trend: Option<f32>) -> (f32, String) {
+        let deposits = view.known_deposits(tile);
+        let collectors = view.count(robot_type);
+        let shortage = trend.is_some_and(|t| t <= 0.0);
+        let mut score = deposits.min(DEPOSIT_SATURATION) as f32 / (collectors + 1) as f32;
+        if shortage {
+            score *= SHORTAGE_FACTOR;
+        }
+
+        let mut rationale = format!("{} gisements connus pour {} collecteur(s)", deposits, collectors);
+        if let Some(trend) = trend {
+            rationale.push_str(&format!(", réserves {:+.2}/tour", trend));
+        }
+        (score, rationale)
+    }
+}
+
+impl FleetPlanner for BalancedPlanner {
+    fn plan(&self, view: &FleetView) -> BuildDecision {
+        if view.out_of_contact > 0 && view.relay_count < MAX_RELAYS {
+            return BuildDecision {
+                robot_type: RobotType::Relay,
+                rationale: format!("{} robot(s) hors de portée radio, {} relais", view.out_of_contact, view.relay_count),
+            };
+        }
+        if view.needs_repairer {
+            return BuildDecision {
+                robot_type: RobotType::Repair,
+                rationale: "robots usés sur le terrain sans réparateur".to_string(),
+            };
+        }
+
+        let explorers = view.count(RobotType::Explorer);
+        let mut candidates = vec![(
+            RobotType::Explorer,
+            view.unexplored * EXPLORATION_WEIGHT / (explorers + 1) as f32,
+            format!("{:.0}% de la carte inexplorée pour {} explorateur(s)", view.unexplored * 100.0, explorers),
+        )];
+        for (robot_type, tile, trend) in [
+            (RobotType::EnergyCollector, TileType::Energy, Some(view.energy_trend)),
+            (RobotType::MineralCollector, TileType::Mineral, Some(view.mineral_trend)),
+            (RobotType::ScientificCollector, TileType::Scientific, None),
+        ] {
+            let (score, rationale) = Self::collector_need(view, robot_type, tile, trend);
+            candidates.push((robot_type, score, rationale));
+        }
+
+        // Premier meilleur score: à égalité, l'ordre ci-dessus départage
+        let mut best = 0;
+        for (i, candidate) in candidates.iter().enumerate() {
+            if candidate.1 > candidates[best].1 {
+                best = i;
+            }
+        }
+        let (robot_type, score, rationale) = candidates.swap_remove(best);
+        if score <= 0.0 {
+            return BuildDecision { robot_type: RobotType::Explorer, rationale: "aucun besoin identifié".to_string() };
+        }
+        BuildDecision { robot_type, rationale }
+    }
+}
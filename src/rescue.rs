@@ -0,0 +1,395 @@
+// src/rescue.rs
+// Pannes d'énergie: un robot à sec reste immobilisé là où il s'est arrêté. La station envoie un
+// collecteur d'énergie lui transférer de quoi rentrer, ou à défaut un robot pour le remorquer
+// jusqu'à la station. Sans secours dans le délai imparti, le robot est abandonné.
+// Avant d'en arriver là, un robot à court d'énergie peut demander par radio une recharge sur
+// le terrain: le collecteur d'énergie le plus proche vient lui céder ses cellules.
+use crate::types::{RobotType, RobotMode};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::events::SimulationEvent;
+use crate::inventory::CargoKind;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Tours d'immobilisation avant d'abandonner un robot qu'aucun secours n'a atteint
+pub const RESCUE_TIMEOUT: u32 = 150;
+// Surcoût énergétique du remorquage, en proportion du déplacement du remorqueur
+pub const TOW_ENERGY_FACTOR: f32 = 1.0;
+
+// Moyen de secours d'un robot en panne
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RescueMethod {
+    EnergyTransfer,   // Un collecteur d'énergie lui cède de quoi rentrer
+    Tow,              // Un robot le ramène en remorque à la station
+}
+
+impl fmt::Display for RescueMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RescueMethod::EnergyTransfer => write!(f, "transfert d'énergie"),
+            RescueMethod::Tow => write!(f, "remorquage"),
+        }
+    }
+}
+
+// Ordre de mission donné par la station à un robot sauveteur (prioritaire sur son comportement)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RescueOrder {
+    Reach((usize, usize)),   // Rejoindre le robot en panne
+    TowHome,                 // Ramener le robot remorqué à la station
+}
+
+// Statistiques des pannes et des secours
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RescueStats {
+    pub stranded: usize,      // Pannes d'énergie
+    pub recharged: usize,     // Robots relancés par transfert d'énergie
+    pub towed: usize,         // Robots ramenés en remorque
+    pub written_off: usize,   // Robots abandonnés
+    pub cargo_lost: u32,      // Minerais et données perdus avec les robots abandonnés
+    pub field_charges: usize,     // Recharges sur le terrain à la demande d'un robot
+    pub energy_delivered: f32,    // Énergie cédée par les collecteurs (secours et recharges)
+}
+
+// Secours en cours pour un robot en panne
+#[derive(Clone, Debug)]
+struct Mission {
+    rescuer: usize,
+    method: RescueMethod,
+    hitch: Option<(usize, usize)>,   // En remorque: position du remorqueur au tour précédent
+}
+
+#[derive(Clone, Debug)]
+struct Breakdown {
+    since: u32,
+    mission: Option<Mission>,
+}
+
+pub struct RescueService {
+    pub timeout: u32,
+    breakdowns: BTreeMap<usize, Breakdown>,   // Robots en panne, par id
+    charges: BTreeMap<usize, usize>,          // Robot demandeur -> collecteur qui vient le recharger
+    pub stats: RescueStats,
+}
+
+impl Default for RescueService {
+    fn default() -> Self {
+        Self::new(RESCUE_TIMEOUT)
+    }
+}
+
+// Deux robots distincts du même tableau, empruntés ensemble
+fn pair_mut(robots: &mut [Robot], a: usize, b: usize) -> (&mut Robot, &mut Robot) {
+    if a < b {
+        let (left, right) = robots.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = robots.split_at_mut(a);
+        (&mut right[0], &mut left[b])
+    }
+}
+
+// Énergie dont un robot a besoin pour rentrer à la station avec sa marge de sécurité
+fn energy_to_get_home(robot: &Robot, map: &Map) -> Option<f32> {
+    robot.energy_to_return(map).map(|needed| needed + robot.safety_margin(needed))
+}
+
+impl RescueService {
+    pub fn new(timeout: u32) -> Self {
+        Self { timeout, breakdowns: BTreeMap::new(), charges: BTreeMap::new(), stats: RescueStats::default() }
+    }
+
+    // Robots en panne et depuis quand
+    pub fn stranded(&self) -> Vec<(usize, u32)> {
+        self.breakdowns.iter().map(|(&id, b)| (id, b.since)).collect()
+    }
+
+    // Robot chargé de secourir un robot en panne et par quel moyen
+    pub fn rescuer_of(&self, robot_id: usize) -> Option<(usize, RescueMethod)> {
+        self.breakdowns.get(&robot_id)?.mission.as_ref().map(|m| (m.rescuer, m.method))
+    }
+
+    // Collecteur d'énergie en route pour recharger un robot
+    pub fn charger_of(&self, robot_id: usize) -> Option<usize> {
+        self.charges.get(&robot_id).copied()
+    }
+
+    // Un tour de secours: constater les pannes, faire avancer les missions, abandonner les
+    // robots hors délai et envoyer des secours aux autres, puis servir les demandes de
+    // recharge (`cell_energy`: énergie d'une cellule cédée). Retourne les événements du tour.
+    pub fn run(&mut self, map: &Map, robots: &mut Vec<Robot>, now: u32, cell_energy: u32) -> Vec<SimulationEvent> {
+        let mut events = Vec::new();
+        self.detect(robots, now, &mut events);
+        self.progress(map, robots, cell_energy, &mut events);
+        self.write_off(robots, now, &mut events);
+        self.dispatch(map, robots, cell_energy);
+        self.charge(map, robots, cell_energy, &mut events);
+        events
+    }
+
+    // Immobilise les robots à court d'énergie
+    fn detect(&mut self, robots: &mut [Robot], now: u32, events: &mut Vec<SimulationEvent>) {
+        for i in 0..robots.len() {
+            if robots[i].energy > 0.0 || robots[i].mode == RobotMode::Stranded {
+                continue;
+            }
+
+            let robot = &mut robots[i];
+            robot.energy = 0.0;
+            robot.mode = RobotMode::Stranded;
+            robot.path_to_station.clear();
+            robot.exploration_target = None;
+            robot.task = None;
+            robot.rescue = None;
+            let id = robot.id;
+            let position = (robot.x, robot.y);
+
+            // Un sauveteur en panne abandonne sa mission
+            for breakdown in self.breakdowns.values_mut() {
+                if breakdown.mission.as_ref().is_some_and(|m| m.rescuer == id) {
+                    breakdown.mission = None;
+                }
+            }
+
+            self.breakdowns.insert(id, Breakdown { since: now, mission: None });
+            self.stats.stranded += 1;
+            events.push(SimulationEvent::RobotStranded { robot_id: id, position });
+        }
+    }
+
+    // Transfert d'énergie au contact, remorquage pas à pas jusqu'à la station
+    fn progress(&mut self, map: &Map, robots: &mut [Robot], cell_energy: u32, events: &mut Vec<SimulationEvent>) {
+        let mut finished = Vec::new();
+
+        for (&id, breakdown) in self.breakdowns.iter_mut() {
+            let Some(mission) = breakdown.mission.as_mut() else { continue };
+            let (Some(s), Some(r)) = (robots.iter().position(|r| r.id == id),
+                                      robots.iter().position(|r| r.id == mission.rescuer)) else {
+                breakdown.mission = None;
+                continue;
+            };
+
+            let stranded_pos = (robots[s].x, robots[s].y);
+            let rescuer_pos = (robots[r].x, robots[r].y);
+
+            match mission.hitch {
+                // Remorquage: le robot en panne prend la place que le remorqueur vient de quitter
+                Some(hitch) => {
+                    // (la station accepte plusieurs robots: il y rejoint le remorqueur arrivé)
+                    let moved = rescuer_pos != hitch || robots[r].is_at_station();
+                    if moved && hitch != stranded_pos {
+                        let cost = map.movement.step_cost(stranded_pos, hitch);
+                        robots[r].energy -= robots[r].energy_for_travel(cost) * TOW_ENERGY_FACTOR;
+                        robots[s].x = hitch.0;
+                        robots[s].y = hitch.1;
+                    }
+                    mission.hitch = Some(rescuer_pos);
+
+                    if robots[s].is_at_station() {
+                        // Le robot rechargera en s'amarrant au prochain tour
+                        robots[s].mode = RobotMode::Idle;
+                        robots[r].rescue = None;
+                        self.stats.towed += 1;
+                        events.push(SimulationEvent::RobotRescued { robot_id: id, rescuer_id: mission.rescuer, method: RescueMethod::Tow });
+                        finished.push(id);
+                    }
+                },
+                None if robots[r].is_next_to(map, stranded_pos) => match mission.method {
+                    RescueMethod::EnergyTransfer => {
+                        match energy_to_get_home(&robots[s], map) {
+                            Some(needed) if robots[r].spare_energy(map, rescuer_pos, cell_energy) >= needed => {
+                                let (rescuer, stranded) = pair_mut(robots, r, s);
+                                self.stats.energy_delivered += rescuer.transfer_energy(stranded, needed, cell_energy);
+                                rescuer.rescue = None;
+                                stranded.mode = RobotMode::Idle;
+                                self.stats.recharged += 1;
+                                events.push(SimulationEvent::RobotRescued { robot_id: id, rescuer_id: mission.rescuer, method: RescueMethod::EnergyTransfer });
+                                finished.push(id);
+                            },
+                            _ => {
+                                // Plus assez d'énergie à céder: un autre secours sera envoyé
+                                robots[r].rescue = None;
+                                breakdown.mission = None;
+                            },
+                        }
+                    },
+                    RescueMethod::Tow => {
+                        // Le robot remorqué est ramené à la station du remorqueur et s'y rattache
+                        (robots[s].home_station_x, robots[s].home_station_y) =
+                            (robots[r].home_station_x, robots[r].home_station_y);
+                        mission.hitch = Some(rescuer_pos);
+                        robots[r].rescue = Some(RescueOrder::TowHome);
+                    },
+                },
+                None => {},
+            }
+        }
+
+        for id in finished {
+            self.breakdowns.remove(&id);
+        }
+    }
+
+    // Abandonne les robots immobilisés trop longtemps (sauf ceux déjà en remorque)
+    fn write_off(&mut self, robots: &mut Vec<Robot>, now: u32, events: &mut Vec<SimulationEvent>) {
+        let expired: Vec<usize> = self.breakdowns.iter()
+            .filter(|(_, b)| b.mission.as_ref().is_none_or(|m| m.hitch.is_none()))
+            .filter(|(_, b)| now.saturating_sub(b.since) >= self.timeout)
+            .map(|(&id, _)| id)
+            .collect();
+
+        for id in expired {
+            if let Some(mission) = self.breakdowns.remove(&id).and_then(|b| b.mission) {
+                if let Some(rescuer) = robots.iter_mut().find(|r| r.id == mission.rescuer) {
+                    rescuer.rescue = None;
+                }
+            }
+
+            if let Some(index) = robots.iter().position(|r| r.id == id) {
+                let robot = robots.remove(index);
+                self.stats.written_off += 1;
+                self.stats.cargo_lost += robot.inventory.total_units();
+                events.push(SimulationEvent::RobotWrittenOff { robot_id: id, position: (robot.x, robot.y) });
+            }
+        }
+
+        // Oublier les robots qui ont disparu autrement
+        self.breakdowns.retain(|id, _| robots.iter().any(|r| r.id == *id));
+    }
+
+    // Envoie le sauveteur disponible le plus proche à chaque robot en panne sans secours.
+    // Un collecteur d'énergie transfère s'il peut céder de quoi rentrer; sinon le robot est
+    // remorqué par qui peut faire l'aller et le retour en remorque.
+    fn dispatch(&mut self, map: &Map, robots: &mut [Robot], cell_energy: u32) {
+        for (&id, breakdown) in self.breakdowns.iter_mut() {
+            if breakdown.mission.is_some() {
+                continue;
+            }
+            let Some(stranded) = robots.iter().find(|r| r.id == id) else { continue };
+            let position = (stranded.x, stranded.y);
+            let needed = energy_to_get_home(stranded, map);
+
+            let mut best: Option<(usize, usize, RescueMethod)> = None;
+            for rescuer in robots.iter() {
+                if rescuer.mode == RobotMode::Stranded || rescuer.rescue.is_some() {
+                    continue;
+                }
+                let Some(travel) = map.travel_cost_to_goal((rescuer.x, rescuer.y), position) else { continue };
+                let reach = rescuer.energy_for_travel(travel);
+
+                let transfer = rescuer.robot_type == RobotType::EnergyCollector
+                    && needed.is_some_and(|given| rescuer.spare_energy(map, position, cell_energy) - reach >= given);
+                // Le robot remorqué est ramené à la station du remorqueur
+                let home = (rescuer.home_station_x, rescuer.home_station_y);
+                let tow = map.travel_cost_to_goal(position, home).is_some_and(|back| {
+                    let towing = rescuer.energy_for_travel(back) * (1.0 + TOW_ENERGY_FACTOR);
+                    reach + towing + rescuer.safety_margin(reach + towing) <= rescuer.energy
+                });
+
+                let method = match (transfer, tow) {
+                    (true, _) => RescueMethod::EnergyTransfer,
+                    (false, true) => RescueMethod::Tow,
+                    (false, false) => continue,
+                };
+                if best.is_none_or(|(_, cost, _)| travel < cost) {
+                    best = Some((rescuer.id, travel, method));
+                }
+            }
+
+            if let Some((rescuer_id, _, method)) = best {
+                if let Some(rescuer) = robots.iter_mut().find(|r| r.id == rescuer_id) {
+                    rescuer.rescue = Some(RescueOrder::Reach(position));
+                    rescuer.task = None;
+                }
+                breakdown.mission = Some(Mission { rescuer: rescuer_id, method, hitch: None });
+            }
+        }
+    }
+
+    // Recharges sur le terrain: le collecteur d'énergie disponible le plus proche rejoint chaque
+    // robot qui en fait la demande et lui cède ses cellules (puis le surplus de sa batterie)
+    fn charge(&mut self, map: &Map, robots: &mut [Robot], cell_energy: u32, events: &mut Vec<SimulationEvent>) {
+        // Abandonner les recharges devenues inutiles (demandeur rentré, rechargé ou hors de
+        // contact) ou impossibles (collecteur en panne ou à vide)
+        self.charges.retain(|&requester, &mut charger| {
+            let wanted = robots.iter().any(|r| r.id == requester && r.charge_requested);
+            let able = robots.iter().any(|r| r.id == charger && r.mode != RobotMode::Stranded
+                && r.inventory.amount(CargoKind::EnergyCell) > 0);
+            if !(wanted && able) {
+                if let Some(charger) = robots.iter_mut().find(|r| r.id == charger) {
+                    charger.rescue = None;
+                }
+            }
+            wanted && able
+        });
+
+        // Faire avancer les recharges en cours
+        let mut finished = Vec::new();
+        for (&requester, &charger) in self.charges.iter() {
+            let (Some(q), Some(c)) = (robots.iter().position(|r| r.id == requester),
+                                      robots.iter().position(|r| r.id == charger)) else { continue };
+            let position = (robots[q].x, robots[q].y);
+
+            if !robots[c].is_next_to(map, position) {
+                robots[c].rescue = Some(RescueOrder::Reach(position));
+                continue;
+            }
+
+            // Le collecteur garde de quoi rentrer à sa station
+            let (charger_robot, robot) = pair_mut(robots, c, q);
+            let spare = charger_robot.spare_energy(map, (charger_robot.x, charger_robot.y), cell_energy);
+            let amount = (robot.max_energy - robot.energy).min(spare);
+            let given = charger_robot.transfer_energy(robot, amount, cell_energy);
+            charger_robot.rescue = None;
+            // Rechargé, le robot reprend sa mission au lieu de rentrer
+            robot.mode = RobotMode::Idle;
+            robot.charge_requested = false;
+
+            self.stats.field_charges += 1;
+            self.stats.energy_delivered += given;
+            events.push(SimulationEvent::RobotCharged { robot_id: requester, charger_id: charger, energy: given });
+            finished.push(requester);
+        }
+        for requester in finished {
+            self.charges.remove(&requester);
+        }
+
+        // Attribuer les nouvelles demandes au collecteur capable de les servir le plus proche
+        for i in 0..robots.len() {
+            let requester = &robots[i];
+            if !requester.charge_requested || requester.mode == RobotMode::Stranded || self.charges.contains_key(&requester.id) {
+                continue;
+            }
+            let position = (requester.x, requester.y);
+
+            let mut best: Option<(usize, usize)> = None;
+            for charger in robots.iter() {
+                if charger.inventory.amount(CargoKind::EnergyCell) == 0
+                    || charger.rescue.is_some() || charger.mode == RobotMode::Stranded {
+                    continue;
+                }
+                let home = (charger.home_station_x, charger.home_station_y);
+                let (Some(travel), Some(back)) = (map.travel_cost_to_goal((charger.x, charger.y), position),
+                                                  map.travel_cost_to_goal(position, home)) else { continue };
+                let trip = charger.energy_for_travel(travel + back);
+                if trip + charger.safety_margin(trip) > charger.energy {
+                    continue;
+                }
+                if best.is_none_or(|(_, cost)| travel < cost) {
+                    best = Some((charger.id, travel));
+                }
+            }
+
+            if let Some((charger_id, _)) = best {
+                let requester_id = robots[i].id;
+                if let Some(charger) = robots.iter_mut().find(|r| r.id == charger_id) {
+                    charger.rescue = Some(RescueOrder::Reach(position));
+                    charger.task = None;
+                }
+                self.charges.insert(requester_id, charger_id);
+            }
+        }
+    }
+}
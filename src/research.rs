@@ -0,0 +1,148 @@
+// src/research.rs
+// Recherche de la station, financée par les données scientifiques collectées. Chaque technologie
+// a plusieurs niveaux de coût croissant; un niveau acquis améliore tous les robots, ceux en
+// service comme ceux construits ensuite. La Terre choisit le projet en cours; à défaut, la
+// station finance la technologie la moins avancée.
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::fmt;
+
+// Niveau maximal de chaque technologie
+pub const MAX_LEVEL: u32 = 3;
+
+// Données scientifiques nécessaires au premier niveau (multiplié par le niveau visé)
+pub const BASE_RESEARCH_COST: u32 = 4;
+
+// Effet de chaque niveau
+pub const BATTERY_BONUS_PER_LEVEL: f32 = 0.2;    // Capacité de batterie
+pub const MOVE_SAVING_PER_LEVEL: f32 = 0.15;     // Énergie de déplacement économisée
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Technology {
+    Optics,       // Portée de vision +1 par niveau
+    Batteries,    // Capacité de batterie +20% par niveau
+    Propulsion,   // Déplacements 15% moins coûteux par niveau
+    Drilling,     // Une unité de plus extraite par collecte et par niveau
+}
+
+impl Technology {
+    pub const ALL: [Technology; 4] = [Technology::Optics, Technology::Batteries, Technology::Propulsion, Technology::Drilling];
+}
+
+impl fmt::Display for Technology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Technology::Optics => write!(f, "optique"),
+            Technology::Batteries => write!(f, "batteries"),
+            Technology::Propulsion => write!(f, "propulsion"),
+            Technology::Drilling => write!(f, "forage"),
+        }
+    }
+}
+
+// Niveaux acquis, appliqués aux robots
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Upgrades {
+    pub optics: u32,
+    pub batteries: u32,
+    pub propulsion: u32,
+    pub drilling: u32,
+}
+
+impl Upgrades {
+    pub fn level(&self, technology: Technology) -> u32 {
+        match technology {
+            Technology::Optics => self.optics,
+            Technology::Batteries => self.batteries,
+            Technology::Propulsion => self.propulsion,
+            Technology::Drilling => self.drilling,
+        }
+    }
+
+    fn level_mut(&mut self, technology: Technology) -> &mut u32 {
+        match technology {
+            Technology::Optics => &mut self.optics,
+            Technology::Batteries => &mut self.batteries,
+            Technology::Propulsion => &mut self.propulsion,
+            Technology::Drilling => &mut self.drilling,
+        }
+    }
+
+    pub fn vision_bonus(&self) -> usize {
+        self.optics as usize
+    }
+
+    pub fn battery_factor(&self) -> f32 {
+        1.0 + self.batteries as f32 * BATTERY_BONUS_PER_LEVEL
+    }
+
+    pub fn move_factor(&self) -> f32 {
+        1.0 - self.propulsion as f32 * MOVE_SAVING_PER_LEVEL
+    }
+
+    // Unités extraites par collecte
+    pub fn mining_yield(&self) -> u32 {
+        1 + self.drilling
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Research {
+    pub upgrades: Upgrades,
+    pub current: Option<Technology>,        // Projet financé en priorité
+    pub funded: HashMap<Technology, u32>,   // Données déjà investies dans le prochain niveau
+}
+
+impl Research {
+    // Coût du prochain niveau (None si la technologie est au niveau maximal)
+    pub fn cost(&self, technology: Technology) -> Option<u32> {
+        let level = self.upgrades.level(technology);
+        (level < MAX_LEVEL).then_some(BASE_RESEARCH_COST * (level + 1))
+    }
+
+    // Choisit le projet en cours; les données déjà investies ailleurs restent acquises
+    pub fn select(&mut self, technology: Technology) -> bool {
+        if self.cost(technology).is_none() {
+            return false;
+        }
+        self.current = Some(technology);
+        true
+    }
+
+    // Investit les données disponibles dans le projet en cours; retourne la technologie dont un
+    // niveau vient d'être acquis
+    pub fn fund(&mut self, available: &mut u32) -> Option<Technology> {
+        if self.current.is_none() {
+            self.current = Technology::ALL.into_iter()
+                .filter(|t| self.cost(*t).is_some())
+                .min_by_key(|t| self.upgrades.level(*t));
+        }
+        let technology = self.current?;
+        let cost = self.cost(technology)?;
+
+        let funded = self.funded.entry(technology).or_insert(0);
+        let spent = (*available).min(cost - *funded);
+        *funded += spent;
+        *available -= spent;
+        if *funded < cost {
+            return None;
+        }
+
+        self.funded.remove(&technology);
+        *self.upgrades.level_mut(technology) += 1;
+        self.current = None;
+        Some(technology)
+    }
+
+    pub fn summary(&self) -> String {
+        let levels = Technology::ALL.iter()
+            .map(|t| format!("{} {}/{}", t, self.upgrades.level(*t), MAX_LEVEL))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.current {
+            Some(t) => format!("{} | En cours: {} {}/{}", levels, t,
+                               self.funded.get(&t).copied().unwrap_or(0), self.cost(t).unwrap_or(0)),
+            None => levels,
+        }
+    }
+}
@@ -1,29 +1,38 @@
 use crate::types::{MAP_SIZE, TileType, RobotType, RobotMode};
 use crate::map::Map;
+use crate::movement::{STEP_COST, DIAGONAL_STEP_COST};
+use crate::exploration::{find_frontiers, is_frontier_cell, rank_frontiers};
 use crate::station::{Station, TerrainData};
-use rand::prelude::*;
-use std::collections::{VecDeque, BinaryHeap, HashMap};
-use std::cmp::Ordering;
+use crate::behavior::{Action, Behavior, default_behavior};
+use crate::traffic::{DETOUR_AFTER, FORCED_YIELD_AFTER};
+use crate::tasks::{Bid, CollectionTask};
+use crate::rescue::RescueOrder;
+use crate::inventory::{CargoKind, Inventory, MASS_MOVE_PENALTY};
+use crate::wear::{Health, CHARGE_CYCLE_DEPTH};
+use crate::events::SimulationEvent;
+use crate::sensors::accumulate;
+use crate::fov::field_of_view;
+use crate::research::Upgrades;
+use crate::crdt::{ChangeSet, VectorClock};
+use std::collections::VecDeque;
 
-// Structure de nœud pour l'algorithme A*
-#[derive(Clone, Eq, PartialEq)]
-struct Node {
-    position: (usize, usize),
-    g_cost: usize,  // Coût depuis le départ
-    f_cost: usize,  // Coût total estimé (g_cost + heuristique)
-}
+// Réserve d'énergie gardée en plus du trajet de retour: fixe + proportion du trajet
+pub const RETURN_SAFETY_MARGIN: f32 = 3.0;
+pub const RETURN_SAFETY_RATIO: f32 = 0.1;
 
-// Implémentation pour le tri dans la file de priorité
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // On inverse pour avoir une min-heap au lieu d'une max-heap
-        other.f_cost.cmp(&self.f_cost)
-    }
-}
+// Pondération de l'énergie et du chargement dans les offres (en cases équivalentes)
+const BID_ENERGY_WEIGHT: f32 = 5.0;
+const BID_CARGO_WEIGHT: f32 = 5.0;
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+// Capacité nominale de la batterie selon le type de robot
+pub fn rated_capacity(robot_type: RobotType) -> f32 {
+    match robot_type {
+        RobotType::Explorer => 80.0,            // Explorateur: endurance moyenne
+        RobotType::EnergyCollector => 120.0,    // Collecteur d'énergie: grande capacité
+        RobotType::MineralCollector => 100.0,   // Collecteur de minerais: bonne endurance
+        RobotType::ScientificCollector => 60.0, // Collecteur scientifique: faible endurance
+        RobotType::Relay => 100.0,              // Relais: reste longtemps en poste
+        RobotType::Repair => 90.0,              // Réparateur: intervient loin de la station
     }
 }
 
@@ -32,8 +41,7 @@ pub struct Robot {
     pub y: usize,
     pub energy: f32,
     pub max_energy: f32,
-    pub minerals: u32,
-    pub scientific_data: u32,
+    pub inventory: Inventory,          // Soute (emplacements définis par le type de robot)
     pub robot_type: RobotType,
     pub mode: RobotMode,
     pub memory: Vec<Vec<TerrainData>>, // Mémoire du robot avec timestamps
@@ -42,17 +50,26 @@ pub struct Robot {
     pub home_station_x: usize,         // Coordonnées X de la station d'origine
     pub home_station_y: usize,         // Coordonnées Y de la station d'origine
     pub last_sync_time: u32,           // Dernière synchronisation avec la station
+    pub clock: VectorClock,            // Versions des connaissances des stations déjà reçues
+    pub changes: ChangeSet,            // Cases apprises depuis la dernière synchronisation
+    pub exploration_target: Option<(usize, usize)>, // Frontière actuellement visée
+    pub in_contact: bool,              // À portée radio de la station (directement ou via relais)
+    pub task: Option<(usize, usize)>,  // Gisement attribué par le marché des tâches
+    pub rescue: Option<RescueOrder>,   // Mission de secours confiée par la station
+    pub charge_requested: bool,        // Recharge sur le terrain demandée à la station
+    pub health: Health,                // Usure des moteurs, des capteurs et de la batterie
+    pub under_repair: bool,            // Immobilisé le temps d'une réparation sur le terrain
+    pub charging: bool,                // Occupe une baie de recharge de son quai
+    pub upgrades: Upgrades,            // Améliorations issues de la recherche de la station
+    stride: f32,                       // Progression vers la case suivante (moteurs usés)
+    behavior: Option<Box<dyn Behavior>>, // Stratégie de contrôle (observer → décider → agir)
 }
 
 impl Robot {
     pub fn new(x: usize, y: usize, robot_type: RobotType) -> Self {
         // Paramètres différents selon le type de robot
-        let (max_energy, energy) = match robot_type {
-            RobotType::Explorer => (80.0, 80.0),           // Explorateur: endurance moyenne
-            RobotType::EnergyCollector => (120.0, 120.0),  // Collecteur d'énergie: grande capacité
-            RobotType::MineralCollector => (100.0, 100.0), // Collecteur de minerais: bonne endurance
-            RobotType::ScientificCollector => (60.0, 60.0), // Collecteur scientifique: faible endurance
-        };
+        let max_energy = rated_capacity(robot_type);
+        let energy = max_energy;
         
         // Initialiser une mémoire vide
         let mut memory = Vec::with_capacity(MAP_SIZE);
@@ -63,6 +80,8 @@ impl Robot {
                     timestamp: 0,
                     robot_id: 0,
                     robot_type: RobotType::Explorer,
+                    tile: TileType::Empty,
+                    confidence: 0.0,
                 }; 
                 MAP_SIZE
             ];
@@ -74,8 +93,7 @@ impl Robot {
             y,
             energy,
             max_energy,
-            minerals: 0,
-            scientific_data: 0,
+            inventory: Inventory::for_robot(robot_type),
             robot_type,
             mode: RobotMode::Exploring, // Commencer directement en mode exploration
             memory,
@@ -84,6 +102,19 @@ impl Robot {
             home_station_x: x,
             home_station_y: y,
             last_sync_time: 0,
+            clock: VectorClock::default(),
+            changes: ChangeSet::default(),
+            exploration_target: None,
+            in_contact: false,
+            task: None,
+            rescue: None,
+            charge_requested: false,
+            charging: false,
+            health: Health::new(),
+            under_repair: false,
+            upgrades: Upgrades::default(),
+            stride: 1.0,
+            behavior: Some(default_behavior(robot_type)),
         }
     }
     
@@ -97,20 +128,25 @@ impl Robot {
         station_y: usize,
         memory: Vec<Vec<TerrainData>>
     ) -> Self {
-        let (max_energy, energy) = match robot_type {
-            RobotType::Explorer => (80.0, 80.0),
-            RobotType::EnergyCollector => (120.0, 120.0),
-            RobotType::MineralCollector => (100.0, 100.0),
-            RobotType::ScientificCollector => (60.0, 60.0),
-        };
+        let max_energy = rated_capacity(robot_type);
+        let energy = max_energy;
+        
+        // Les cases déjà connues seront transmises à la première synchronisation
+        let mut changes = ChangeSet::default();
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                if memory[y][x].explored {
+                    changes.mark((x, y));
+                }
+            }
+        }
         
         Self {
             x,
             y,
             energy,
             max_energy,
-            minerals: 0,
-            scientific_data: 0,
+            inventory: Inventory::for_robot(robot_type),
             robot_type,
             mode: RobotMode::Exploring,
             memory,
@@ -119,6 +155,19 @@ impl Robot {
             home_station_x: station_x,
             home_station_y: station_y,
             last_sync_time: 0,
+            clock: VectorClock::default(),
+            changes,
+            exploration_target: None,
+            in_contact: false,
+            task: None,
+            rescue: None,
+            charge_requested: false,
+            charging: false,
+            health: Health::new(),
+            under_repair: false,
+            upgrades: Upgrades::default(),
+            stride: 1.0,
+            behavior: Some(default_behavior(robot_type)),
         }
     }
     
@@ -129,6 +178,8 @@ impl Robot {
             RobotType::EnergyCollector => "P", // Power collector
             RobotType::MineralCollector => "M",
             RobotType::ScientificCollector => "S",
+            RobotType::Relay => "R",
+            RobotType::Repair => "T", // Technicien
         }
     }
     
@@ -139,447 +190,551 @@ impl Robot {
             RobotType::EnergyCollector => 10,  // Vert vif
             RobotType::MineralCollector => 13, // Magenta vif
             RobotType::ScientificCollector => 12, // Bleu vif
+            RobotType::Relay => 14,            // Cyan vif
+            RobotType::Repair => 208,          // Orange
         }
     }
     
-    // Mise à jour de la mémoire (exploration)
-    pub fn update_memory(&mut self, map: &Map, station: &Station) {
-        // Marquer la case actuelle comme explorée avec timestamp
-        self.memory[self.y][self.x] = TerrainData {
-            explored: true,
-            timestamp: station.current_time,
-            robot_id: self.id,
-            robot_type: self.robot_type,
-        };
-        
-        // Explorer les cases adjacentes (vision)
-        let vision_range = match self.robot_type {
+    // Portée de vision selon le type de robot et l'optique acquise, réduite par des capteurs usés
+    pub fn vision_range(&self) -> usize {
+        let range = match self.robot_type {
             RobotType::Explorer => 3, // L'explorateur voit plus loin
             _ => 2,                   // Les autres types ont une vision standard
         };
-        
-        for dy in -vision_range..=vision_range {
-            for dx in -vision_range..=vision_range {
-                let nx = self.x as isize + dx;
-                let ny = self.y as isize + dy;
+        self.health.vision(range + self.upgrades.vision_bonus())
+    }
+    
+    // Mise à jour de la mémoire (exploration)
+    pub fn update_memory(&mut self, map: &Map, station: &mut Station) {
+        // Marquer la case actuelle comme explorée avec timestamp
+        let here = map.get_tile(self.x, self.y);
+        self.record(self.x, self.y, here, 1.0, station.current_time);
+        
+        // Relever les cases du champ de vision (portée circulaire, masquées par les obstacles)
+        for (nx, ny) in field_of_view(map, (self.x, self.y), self.vision_range()) {
+            // Si la case n'est pas encore explorée ou si notre info est plus récente
+            if !self.memory[ny][nx].explored || 
+               self.memory[ny][nx].timestamp < station.current_time {
                 
-                if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    
-                    // Si la case n'est pas encore explorée ou si notre info est plus récente
-                    if !self.memory[ny][nx].explored || 
-                       self.memory[ny][nx].timestamp < station.current_time {
-                        
-                        // Mettre à jour avec les connaissances actuelles
-                        self.memory[ny][nx] = TerrainData {
-                            explored: true,
-                            timestamp: station.current_time,
-                            robot_id: self.id,
-                            robot_type: self.robot_type,
-                        };
-                    }
-                }
+                // Capteurs parfaits par défaut; avec le modèle bruité, la case peut être mal classée
+                let (tile, confidence) = match &station.sensors {
+                    Some(model) => model.observe(map, (self.x, self.y), (nx, ny), &mut station.rng),
+                    None => (map.get_tile(nx, ny), 1.0),
+                };
+                self.record(nx, ny, tile, confidence, station.current_time);
             }
         }
     }
     
+    // Enregistre une observation, cumulée avec ce que le robot savait déjà de la case
+    fn record(&mut self, x: usize, y: usize, tile: TileType, confidence: f32, time: u32) {
+        let (tile, confidence) = accumulate(&self.memory[y][x], tile, confidence);
+        self.learn((x, y), TerrainData {
+            explored: true,
+            timestamp: time,
+            robot_id: self.id,
+            robot_type: self.robot_type,
+            tile,
+            confidence,
+        });
+    }
+    
+    // Remplace ce que le robot sait d'une case; elle sera transmise à la prochaine synchronisation
+    pub fn learn(&mut self, position: (usize, usize), data: TerrainData) {
+        self.memory[position.1][position.0] = data;
+        self.changes.mark(position);
+    }
+    
     // Méthode principale de mise à jour
     pub fn update(&mut self, map: &mut Map, station: &mut Station) {
+        // En panne: immobile jusqu'à l'arrivée des secours
+        if self.mode == RobotMode::Stranded {
+            return;
+        }
+        
+        // Usure des capteurs et défaillances aléatoires des composants usés
+        if let Some(component) = self.health.wear_tick(&mut station.rng) {
+            station.maintenance.stats.failures += 1;
+            station.log_event(SimulationEvent::ComponentFailure { robot_id: self.id, component });
+            self.refresh_capacity();
+        }
+        
         // Consommer de l'énergie (métabolisme de base)
         self.energy -= 0.1;
         
-        // Vérifier si le robot doit retourner à la station
-        if self.should_return_to_station(map) {
-            self.mode = RobotMode::ReturnToStation;
-            self.plan_path_to_station(map);
+        // Si à la station, décharger, recharger et synchroniser; le robot reste dans sa baie tant
+        // que la recharge se poursuit
+        if self.is_at_station() {
+            if self.dock(station) {
+                self.perform(Action::Idle, map, station);
+                self.update_memory(map, station);
+                return;
+            }
+        } else {
+            self.charging = false;
         }
         
-        // Pour les collecteurs, vérifier s'il reste des ressources à collecter
-        if self.robot_type != RobotType::Explorer && self.mode == RobotMode::Exploring {
-            // Si aucune ressource de son type n'est disponible, retourner à la station
-            if self.find_nearest_resource(map).is_none() {
-                // S'il n'est pas déjà à la station
-                if self.x != self.home_station_x || self.y != self.home_station_y {
-                    self.mode = RobotMode::ReturnToStation;
-                    self.plan_path_to_station(map);
-                }
-            }
+        // Un collecteur d'énergie à court puise dans les cellules qu'il transporte
+        let cell = station.economy.cell_energy as f32;
+        while self.is_low_on_energy(map) && self.energy + cell <= self.max_energy
+            && self.inventory.take(CargoKind::EnergyCell, 1) == 1 {
+            self.energy += cell;
         }
         
-        // Si à la station, recharger, synchroniser et changer de mode
-        if self.x == self.home_station_x && self.y == self.home_station_y {
-            // Recharger et décharger
-            self.energy = self.max_energy;
-            station.deposit_resources(self.minerals, self.scientific_data);
-            self.minerals = 0;
-            self.scientific_data = 0;
-            
-            // Synchroniser les connaissances avec la station
-            if station.current_time > self.last_sync_time {
-                station.share_knowledge(self);
-                self.last_sync_time = station.current_time;
-            }
+        // Une mission de secours passe avant le comportement du robot
+        if let Some(order) = self.rescue {
+            let action = match order {
+                RescueOrder::Reach(target) if self.is_next_to(map, target) => Action::Idle,
+                RescueOrder::Reach(target) => Action::MoveTo(target),
+                RescueOrder::TowHome => Action::ReturnToStation,
+            };
+            self.perform(action, map, station);
+        } else if self.under_repair && !self.is_low_on_energy(map) {
+            // Rester au contact du robot de réparation jusqu'à la remise en état
+            self.perform(Action::Idle, map, station);
+        } else {
+            // Laisser le comportement du robot observer, décider et agir
+            let mut behavior = self.behavior.take()
+                .unwrap_or_else(|| default_behavior(self.robot_type));
+            let observation = behavior.observe(self, map, station);
+            let action = behavior.decide(&observation);
+            behavior.act(self, action, map, station);
+            self.behavior = Some(behavior);
+        }
+        
+        // À court d'énergie loin de la station: demander par radio une recharge sur le terrain
+        self.charge_requested = self.in_contact
+            && !self.is_at_station()
+            && self.robot_type != RobotType::EnergyCollector
+            && self.is_low_on_energy(map);
+        
+        // Mettre à jour la mémoire
+        self.update_memory(map, station);
+    }
+    
+    // Remplacer la stratégie de contrôle du robot
+    pub fn set_behavior(&mut self, behavior: Box<dyn Behavior>) {
+        self.behavior = Some(behavior);
+    }
+    
+    // Nom de la stratégie de contrôle actuelle
+    pub fn behavior_name(&self) -> &str {
+        self.behavior.as_ref().map(|b| b.name()).unwrap_or("aucun")
+    }
+    
+    pub fn is_at_station(&self) -> bool {
+        self.x == self.home_station_x && self.y == self.home_station_y
+    }
+    
+    // Case atteignable en un pas depuis la position actuelle
+    pub fn is_next_to(&self, map: &Map, pos: (usize, usize)) -> bool {
+        map.movement.neighbors((self.x, self.y), |x, y| map.is_valid_position(x, y)).contains(&pos)
+    }
+    
+    // Équipe le robot des améliorations acquises par la station
+    pub fn apply_upgrades(&mut self, upgrades: Upgrades) {
+        self.upgrades = upgrades;
+        self.refresh_capacity();
+    }
+    
+    // Capacité de la batterie selon son usure et les améliorations
+    fn refresh_capacity(&mut self) {
+        self.max_energy = rated_capacity(self.robot_type) * self.health.battery * self.upgrades.battery_factor();
+        self.energy = self.energy.min(self.max_energy);
+    }
+    
+    // Recharge, révision, dépôt de l'inventaire et synchronisation des connaissances
+    fn dock(&mut self, station: &mut Station) -> bool {
+        station.maintain(&mut self.health);
+        self.refresh_capacity();
+        for i in 0..self.inventory.slots().len() {
+            let kind = self.inventory.slots()[i].kind;
             
-            // Changer de mode après avoir rechargé
-            match self.robot_type {
-                RobotType::Explorer => {
-                    // L'explorateur retourne explorer
-                    self.mode = RobotMode::Exploring;
-                },
-                _ => {
-                    // Les collecteurs cherchent des ressources
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        self.path_to_station = self.find_path(map, resource_pos);
-                        self.mode = RobotMode::Collecting;
-                    } else {
-                        // Si pas de ressource trouvée, rester à la station en mode Idle
-                        self.mode = RobotMode::Idle;
-                    }
-                }
+            // En mission de secours, le collecteur garde ses cellules pour les céder
+            if kind == CargoKind::EnergyCell && self.rescue.is_some() {
+                continue;
             }
+            let amount = self.inventory.take(kind, u32::MAX);
+            station.deposit_at((self.x, self.y), kind, amount);
         }
         
-        // Logique de déplacement selon le mode
-        match self.mode {
-            RobotMode::Idle => {
-                // Rester sur place, mais normalement on ne devrait pas rester longtemps en idle
-                if self.robot_type == RobotType::Explorer {
-                    self.mode = RobotMode::Exploring;
-                }
+        // Un robot déjà à quai (remorqué, resté sur place) demande une baie pour recharger; la
+        // baie délivre au plus son débit par tour, pris sur les réserves du quai
+        let wanted = self.max_energy - self.energy;
+        if !self.charging && wanted >= 1.0 {
+            self.charging = station.request_dock(self, (self.x, self.y));
+        }
+        let delivered = if self.charging { station.draw_charge((self.x, self.y), wanted) } else { 0.0 };
+        
+        // Un cycle de charge est compté quand la batterie repasse au-dessus du seuil de décharge
+        let depth = self.max_energy * CHARGE_CYCLE_DEPTH;
+        if self.energy < depth && self.energy + delivered >= depth {
+            self.health.charge_cycle();
+        }
+        self.energy = (self.energy + delivered).min(self.max_energy);
+        
+        if station.current_time > self.last_sync_time {
+            station.share_knowledge(self);
+            self.last_sync_time = station.current_time;
+        }
+        
+        self.charging = delivered > 0.0 && self.energy + 1.0 < self.max_energy;
+        self.charging
+    }
+    
+    // Exécuter une action décidée par le comportement du robot
+    pub fn perform(&mut self, action: Action, map: &mut Map, station: &mut Station) {
+        // Quitter l'exploration libère la frontière réservée
+        if action != Action::Explore && self.exploration_target.is_some() {
+            self.exploration_target = None;
+            station.release_frontier(self.id);
+        }
+        
+        match action {
+            Action::Idle => {
+                self.mode = RobotMode::Idle;
+                self.path_to_station.clear();
             },
-            RobotMode::Exploring => {
-                // Si c'est un collecteur, vérifier s'il y a des ressources à proximité
-                if self.robot_type != RobotType::Explorer {
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        let distance = self.heuristic((self.x, self.y), resource_pos);
-                        if distance <= 5 {  // Distance de détection
-                            self.path_to_station = self.find_path(map, resource_pos);
-                            self.mode = RobotMode::Collecting;
-                            return;
-                        }
-                    }
-                }
-                
-                // Sinon, explorer normalement
-                self.explore_move(map);
+            Action::Explore => {
+                self.mode = RobotMode::Exploring;
+                self.explore_move(map, station);
             },
-            RobotMode::Collecting => {
-                // Si on est sur la ressource cible, la collecter
-                let tile = map.get_tile(self.x, self.y);
-                let can_collect = match (self.robot_type, tile) {
-                    (RobotType::EnergyCollector, TileType::Energy) => true,
-                    (RobotType::MineralCollector, TileType::Mineral) => true,
-                    (RobotType::ScientificCollector, TileType::Scientific) => true,
-                    _ => false,
-                };
+            Action::MoveTo(target) => {
+                self.mode = RobotMode::Collecting;
                 
-                if can_collect {
-                    self.collect_resources(map);
-                } else if !self.path_to_station.is_empty() {
-                    // Suivre le chemin vers la ressource
-                    let next = self.path_to_station.pop_front().unwrap();
-                    self.move_to(next.0, next.1);
-                } else {
-                    // Si le chemin est vide mais qu'on n'est pas sur la ressource, chercher une autre ressource
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        self.path_to_station = self.find_path(map, resource_pos);
-                    } else {
-                        // Si plus de ressources, retourner à la station
-                        self.mode = RobotMode::ReturnToStation;
-                        self.plan_path_to_station(map);
-                    }
+                // Replanifier si le chemin courant ne mène pas à la cible
+                if self.path_to_station.back() != Some(&target) {
+                    self.path_to_station = self.find_path(map, target);
                 }
+                self.advance(map, station);
+            },
+            Action::Collect => {
+                self.mode = RobotMode::Collecting;
+                self.path_to_station.clear();
+                self.collect_resources(map);
             },
-            RobotMode::ReturnToStation => {
+            Action::ReturnToStation => {
+                let home = (self.home_station_x, self.home_station_y);
+                if self.mode != RobotMode::ReturnToStation || self.path_to_station.back() != Some(&home) {
+                    self.plan_path_to_station(map);
+                }
+                self.mode = RobotMode::ReturnToStation;
+                
                 if !self.path_to_station.is_empty() {
-                    // Suivre le chemin vers la station
-                    let next = self.path_to_station.pop_front().unwrap();
-                    self.move_to(next.0, next.1);
+                    self.advance(map, station);
+                } else if !self.is_at_station() {
+                    // Si on ne peut pas générer de chemin, revenir en mode exploration
+                    self.mode = RobotMode::Exploring;
                 } else {
-                    // Si le chemin est vide mais qu'on n'est pas à la station, replanifier
-                    if self.x != self.home_station_x || self.y != self.home_station_y {
-                        self.plan_path_to_station(map);
-                        if !self.path_to_station.is_empty() {
-                            let next = self.path_to_station.pop_front().unwrap();
-                            self.move_to(next.0, next.1);
-                        } else {
-                            // Si on ne peut pas générer de chemin, revenir en mode exploration
-                            self.mode = RobotMode::Exploring;
-                        }
-                    } else {
-                        // Si on est à la station, passer en mode idle
-                        self.mode = RobotMode::Idle;
-                    }
+                    self.mode = RobotMode::Idle;
                 }
             }
         }
-        
-        // Mettre à jour la mémoire
-        self.update_memory(map, station);
     }
     
-    // Déplacement d'exploration intelligent
-    fn explore_move(&mut self, map: &Map) {
-        // Chercher les cases non explorées à proximité
-        let mut unexplored_tiles = Vec::new();
-        let vision_range = 5; // Portée de détection des cases non explorées
-        
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                // Si la case n'est pas explorée
-                if !self.memory[y][x].explored {
-                    // Calculer la distance avec la position actuelle
-                    let distance = self.heuristic((self.x, self.y), (x, y));
-                    if distance <= vision_range {
-                        unexplored_tiles.push((x, y, distance));
-                    }
-                }
+    // Exploration par frontières: viser le groupe de cases frontières au meilleur
+    // gain d'information, sans dépasser le budget de retour ni doubler un autre robot
+    fn explore_move(&mut self, map: &Map, station: &mut Station) {
+        let here = (self.x, self.y);
+        let home = (self.home_station_x, self.home_station_y);
+        
+        // Abandonner la cible si elle est atteinte ou n'est plus une frontière
+        if let Some(target) = self.exploration_target {
+            if target == here || !is_frontier_cell(&self.memory, map, target) {
+                self.exploration_target = None;
+                station.release_frontier(self.id);
             }
         }
         
-        // Si des cases non explorées sont trouvées, aller vers la plus proche
-        if !unexplored_tiles.is_empty() {
-            // Trier par distance
-            unexplored_tiles.sort_by_key(|&(_, _, dist)| dist);
+        if self.exploration_target.is_none() {
+            let frontiers = find_frontiers(&self.memory, map, self.vision_range());
+            // Arrivé à la frontière, le robot doit encore pouvoir rentrer sans être à court
+            let candidates = rank_frontiers(frontiers, map, here, home, self.energy,
+                                            |to, back| self.energy_for_travel(to) + self.return_threshold(self.energy_for_travel(back)));
             
-            // Trouver un chemin vers la case non explorée la plus proche
-            let target = (unexplored_tiles[0].0, unexplored_tiles[0].1);
-            let path = self.find_path(map, target);
+            // Éviter une frontière déjà visée par un autre robot, sauf s'il n'y a pas le choix
+            let claim_radius = self.vision_range() * 2;
+            let choice = candidates.iter()
+                .find(|c| !station.is_frontier_claimed(c.frontier.target, self.id, claim_radius))
+                .or(candidates.first());
             
-            if !path.is_empty() {
-                let next = path[0];
-                self.move_to(next.0, next.1);
-                return;
-            }
-        }
-        
-        // Si pas de cases non explorées à proximité ou si on ne peut pas y aller,
-        // faire un mouvement aléatoire comme avant
-        let mut rng = rand::thread_rng();
-        let mut possible_moves = Vec::new();
-        
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-                
-                let nx = self.x as isize + dx;
-                let ny = self.y as isize + dy;
-                
-                if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize 
-                   && map.is_valid_position(nx as usize, ny as usize) {
-                    possible_moves.push((nx as usize, ny as usize));
+            match choice {
+                Some(candidate) => {
+                    self.exploration_target = Some(candidate.frontier.target);
+                    station.claim_frontier(self.id, candidate.frontier.target);
+                },
+                None => {
+                    // Aucune frontière atteignable avec l'énergie restante: rentrer
+                    station.release_frontier(self.id);
+                    if here != home {
+                        self.mode = RobotMode::ReturnToStation;
+                        self.plan_path_to_station(map);
+                    }
+                    return;
                 }
             }
         }
         
-        if !possible_moves.is_empty() {
-            let (nx, ny) = possible_moves[rng.gen_range(0..possible_moves.len())];
-            self.move_to(nx, ny);
+        if let Some(target) = self.exploration_target {
+            let path = self.find_path(map, target);
+            if !path.is_empty() {
+                self.path_to_station = path;
+                self.advance(map, station);
+            } else {
+                // Cible inaccessible: en choisir une autre au prochain tour
+                self.exploration_target = None;
+                station.release_frontier(self.id);
+            }
         }
     }
     
-    // Collecte de ressources selon le type de robot
+    // Coût énergétique par case selon le type de robot et la propulsion, alourdi par la masse transportée
+    fn move_cost_factor(&self) -> f32 {
+        let empty = match self.robot_type {
+            RobotType::Explorer => 0.3,
+            RobotType::EnergyCollector => 0.4,
+            RobotType::MineralCollector => 0.5,
+            RobotType::ScientificCollector => 0.6,
+            RobotType::Relay => 0.3,
+            RobotType::Repair => 0.4,
+        };
+        empty * self.upgrades.move_factor() * (1.0 + self.inventory.mass() * MASS_MOVE_PENALTY)
+    }
+    
+    // Énergie nécessaire pour parcourir un trajet d'un coût donné (déplacement + métabolisme,
+    // payé plus longtemps par un robot ralenti par ses moteurs)
+    pub fn energy_for_travel(&self, cost: usize) -> f32 {
+        let tiles = cost as f32 / STEP_COST as f32;
+        tiles * (self.move_cost_factor() + 0.1 / self.health.speed())
+    }
+    
+    // Collecte de la ressource sous le robot, s'il a un emplacement libre pour elle (le forage
+    // amélioré extrait plusieurs unités du gisement, dans la limite de la place disponible)
     fn collect_resources(&mut self, map: &mut Map) {
         let tile = map.get_tile(self.x, self.y);
         
-        match (self.robot_type, tile) {
-            (RobotType::EnergyCollector, TileType::Energy) => {
-                if self.energy < self.max_energy {
-                    self.energy += 10.0;
-                    if self.energy > self.max_energy {
-                        self.energy = self.max_energy;
-                    }
-                    map.consume_resource(self.x, self.y);
-                }
-            },
-            (RobotType::MineralCollector, TileType::Mineral) => {
-                self.minerals += 1;
-                map.consume_resource(self.x, self.y);
-            },
-            (RobotType::ScientificCollector, TileType::Scientific) => {
-                self.scientific_data += 1;
+        if let Some(kind) = CargoKind::from_tile(&tile) {
+            if self.inventory.add(kind, self.upgrades.mining_yield()) > 0 {
                 map.consume_resource(self.x, self.y);
-            },
-            _ => {
-                // Si pas de ressource à collecter, explorer
-                self.explore_move(map);
             }
         }
-        
-        // Après avoir collecté, vérifier s'il reste des ressources
-        if let Some(resource_pos) = self.find_nearest_resource(map) {
-            self.path_to_station = self.find_path(map, resource_pos);
-        } else {
-            // Si plus de ressources, retourner à la station
-            self.mode = RobotMode::ReturnToStation;
-            self.plan_path_to_station(map);
+    }
+    
+    // Énergie nécessaire pour rentrer à la station depuis la position actuelle
+    // (même modèle de coût que les déplacements; None si aucun chemin)
+    pub fn energy_to_return(&self, map: &Map) -> Option<f32> {
+        map.travel_cost_to_goal((self.x, self.y), (self.home_station_x, self.home_station_y))
+            .map(|cost| self.energy_for_travel(cost))
+    }
+    
+    // Marge de sécurité à conserver en plus de l'énergie d'un trajet
+    pub fn safety_margin(&self, travel_energy: f32) -> f32 {
+        RETURN_SAFETY_MARGIN + travel_energy * RETURN_SAFETY_RATIO
+    }
+    
+    // Énergie en deçà de laquelle un robot à `needed` de sa station doit rentrer: un pas de plus
+    // (au pire en diagonale) ne lui laisserait plus de quoi revenir avec la marge de sécurité
+    fn return_threshold(&self, needed: f32) -> f32 {
+        needed + self.energy_for_travel(DIAGONAL_STEP_COST) + self.safety_margin(needed)
+    }
+    
+    // Énergie juste suffisante pour rentrer; sans chemin connu, garder un seuil fixe
+    pub fn is_low_on_energy(&self, map: &Map) -> bool {
+        match self.energy_to_return(map) {
+            Some(needed) => self.energy <= self.return_threshold(needed),
+            None => self.energy < self.max_energy * 0.3,
         }
     }
     
     // Vérifier s'il faut retourner à la station
-    fn should_return_to_station(&self, map: &Map) -> bool {
-        // Retourner si énergie faible
-        if self.energy < self.max_energy * 0.3 {
+    pub(crate) fn should_return_to_station(&self, map: &Map) -> bool {
+        if self.is_low_on_energy(map) {
             return true;
         }
         
-        // Retourner si inventaire plein (selon le type)
-        match self.robot_type {
-            RobotType::MineralCollector => self.minerals >= 5,
-            RobotType::ScientificCollector => self.scientific_data >= 3,
-            _ => false
-        }
+        // Retourner si un emplacement de la soute est plein
+        self.inventory.is_full()
     }
     
-    // Planifier un chemin vers la station
-    fn plan_path_to_station(&mut self, map: &Map) {
-        let target = (self.home_station_x, self.home_station_y);
-        self.path_to_station = self.find_path(map, target);
+    // Taux de remplissage de la soute (0 à 1)
+    pub fn cargo_load(&self) -> f32 {
+        self.inventory.load()
     }
     
-    // Trouver la ressource la plus proche selon le type du robot
-    fn find_nearest_resource(&self, map: &Map) -> Option<(usize, usize)> {
-        let target_resource = match self.robot_type {
-            RobotType::Explorer => None,  // L'explorateur se concentre sur l'exploration
-            RobotType::EnergyCollector => Some(TileType::Energy),
-            RobotType::MineralCollector => Some(TileType::Mineral),
-            RobotType::ScientificCollector => Some(TileType::Scientific),
+    // Énergie que le robot peut céder en gardant de quoi rentrer: cellules transportées et
+    // surplus de batterie au-delà du retour (avec marge) depuis `meeting`
+    pub fn spare_energy(&self, map: &Map, meeting: (usize, usize), cell_energy: u32) -> f32 {
+        let cells = (self.inventory.amount(CargoKind::EnergyCell) * cell_energy) as f32;
+        let home = (self.home_station_x, self.home_station_y);
+        let kept = match map.travel_cost_to_goal(meeting, home) {
+            Some(cost) => {
+                let back = self.energy_for_travel(cost);
+                back + self.safety_margin(back)
+            },
+            None => return cells,
         };
+        cells + (self.energy - kept).max(0.0)
+    }
+    
+    // Cède jusqu'à `amount` d'énergie à un autre robot (dans la limite de sa batterie): des
+    // cellules entières tant qu'elles tiennent dans ce montant, la batterie pour le reste. Une
+    // cellule entamée recharge d'abord la batterie du donneur, rien n'est perdu.
+    // Retourne l'énergie transférée.
+    pub fn transfer_energy(&mut self, other: &mut Robot, amount: f32, cell_energy: u32) -> f32 {
+        let amount = amount.min(other.max_energy - other.energy).max(0.0);
+        let cell = cell_energy as f32;
+        
+        let mut given = 0.0;
+        while given + cell <= amount && self.inventory.take(CargoKind::EnergyCell, 1) == 1 {
+            given += cell;
+        }
         
-        // Si pas de ressource cible, retourner None
-        let target_resource = match target_resource {
-            Some(res) => res,
-            None => return None,
-        };
+        let remainder = amount - given;
+        if remainder > 0.0 && self.energy + cell <= self.max_energy
+            && self.inventory.take(CargoKind::EnergyCell, 1) == 1 {
+            self.energy += cell;
+        }
+        let from_battery = remainder.min(self.energy).max(0.0);
+        self.energy -= from_battery;
+        given += from_battery;
         
-        // Chercher la ressource la plus proche
-        let mut nearest = None;
-        let mut min_distance = usize::MAX;
+        other.energy += given;
+        given
+    }
+    
+    // Offre pour une tâche de collecte: trajet en cases, majoré si l'énergie est basse ou la
+    // soute chargée. Pas d'offre si le robot ne collecte pas cette ressource, doit rentrer,
+    // est plein ou ne peut pas faire l'aller-retour jusqu'à la station.
+    pub fn bid_for_task(&self, map: &Map, task: &CollectionTask) -> Option<Bid> {
+        if self.target_resource().as_ref() != Some(&task.resource)
+            || self.mode == RobotMode::ReturnToStation
+            || self.rescue.is_some()
+            || self.should_return_to_station(map)
+            || self.cargo_load() >= 1.0 {
+            return None;
+        }
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                if map.get_tile(x, y) == target_resource {
-                    let distance = self.heuristic((self.x, self.y), (x, y));
-                    if distance < min_distance {
-                        min_distance = distance;
-                        nearest = Some((x, y));
-                    }
-                }
-            }
+        let travel = map.travel_cost_to_goal((self.x, self.y), task.position)?;
+        let back = map.travel_cost_to_goal(task.position, (self.home_station_x, self.home_station_y))?;
+        let round_trip = self.energy_for_travel(travel + back);
+        if round_trip + self.safety_margin(round_trip) > self.energy {
+            return None;
         }
         
-        nearest
+        let travel_tiles = travel / STEP_COST;
+        let cost = travel_tiles as f32
+            + (1.0 - self.energy / self.max_energy) * BID_ENERGY_WEIGHT
+            + self.cargo_load() * BID_CARGO_WEIGHT;
+        
+        Some(Bid { robot_id: self.id, task_id: task.id, cost, travel_tiles })
     }
     
-    // Algorithme A* pour trouver le chemin optimal
-    fn find_path(&self, map: &Map, target: (usize, usize)) -> VecDeque<(usize, usize)> {
-        let start = (self.x, self.y);
-        
-        // Si déjà à destination
-        if start == target {
-            return VecDeque::new();
+    // Planifier un chemin vers la station (champ de distance partagé par tous les robots)
+    fn plan_path_to_station(&mut self, map: &Map) {
+        let target = (self.home_station_x, self.home_station_y);
+        self.path_to_station = map.path_to_goal((self.x, self.y), target);
+    }
+    
+    // Type de ressource collecté par ce robot
+    pub(crate) fn target_resource(&self) -> Option<TileType> {
+        // Celle du premier emplacement de la soute (aucune pour l'explorateur et le relais)
+        self.inventory.primary().map(CargoKind::tile)
+    }
+    
+    // Chemin optimal vers une case (A* partagé de la carte)
+    pub fn find_path(&self, map: &Map, target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        map.find_path((self.x, self.y), target)
+    }
+    
+    // Déplacement vers une position
+    // Avance d'un pas le long de `path_to_station` en respectant la circulation: attendre si la
+    // case est prise, contourner après quelques tours, céder le passage en cas d'interblocage
+    fn advance(&mut self, map: &Map, station: &mut Station) {
+        // Moteurs usés: le robot n'avance qu'une partie des tours
+        self.stride = (self.stride + self.health.speed()).min(1.0);
+        if self.stride < 1.0 {
+            return;
         }
         
-        let mut open_set = BinaryHeap::new();
-        let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
-        let mut g_score: HashMap<(usize, usize), usize> = HashMap::new();
+        let here = (self.x, self.y);
+        let (next, goal) = match (self.path_to_station.front(), self.path_to_station.back()) {
+            (Some(&next), Some(&goal)) => (next, goal),
+            _ => return,
+        };
         
-        // Initialiser les valeurs de départ
-        g_score.insert(start, 0);
-        open_set.push(Node {
-            position: start,
-            g_cost: 0,
-            f_cost: self.heuristic(start, target),
-        });
+        // Entrer au quai pour s'y arrêter demande une baie libre; sinon attendre son tour à côté
+        // de la station (un robot qui ne fait que traverser n'en demande pas)
+        let home = (self.home_station_x, self.home_station_y);
+        let docking = next == home && goal == home && !self.charging;
+        if docking && !station.request_dock(self, next) {
+            station.traffic.wait(self.id, next);
+            return;
+        }
         
-        while let Some(current) = open_set.pop() {
-            let current_pos = current.position;
-            
-            // Si on est arrivé à destination
-            if current_pos == target {
-                // Reconstruire le chemin
-                let mut path = VecDeque::new();
-                let mut current = target;
-                
-                while current != start {
-                    path.push_front(current);
-                    current = *came_from.get(&current).unwrap();
+        let blocker = match station.traffic.blocker(self.id, next, station.current_time) {
+            None => {
+                if docking {
+                    self.charging = true;
                 }
-                
-                return path;
-            }
-            
-            // Examiner tous les voisins
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    if dx == 0 && dy == 0 {
-                        continue; // Ignorer la position actuelle
-                    }
-                    
-                    let nx = current_pos.0 as isize + dx;
-                    let ny = current_pos.1 as isize + dy;
-                    
-                    // Vérifier si la position est valide
-                    if nx < 0 || nx >= MAP_SIZE as isize || ny < 0 || ny >= MAP_SIZE as isize {
-                        continue;
-                    }
-                    
-                    let neighbor = (nx as usize, ny as usize);
-                    
-                    // Vérifier si c'est un obstacle
-                    if !map.is_valid_position(neighbor.0, neighbor.1) {
-                        continue;
-                    }
-                    
-                    // Calculer le nouveau coût
-                    let tentative_g_score = g_score[&current_pos] + 1;
-                    
-                    // Si on a trouvé un meilleur chemin
-                    if !g_score.contains_key(&neighbor) || tentative_g_score < g_score[&neighbor] {
-                        came_from.insert(neighbor, current_pos);
-                        g_score.insert(neighbor, tentative_g_score);
-                        
-                        let f_score = tentative_g_score + self.heuristic(neighbor, target);
-                        open_set.push(Node {
-                            position: neighbor,
-                            g_cost: tentative_g_score,
-                            f_cost: f_score,
-                        });
-                    }
+                self.path_to_station.pop_front();
+                self.step(map, station, next);
+                return;
+            },
+            Some(blocker) => blocker,
+        };
+        
+        let waited = station.traffic.wait(self.id, next);
+        
+        // Contourner le robot gênant si un autre chemin existe
+        if waited >= DETOUR_AFTER {
+            let avoid = station.traffic.avoided_tiles(self.id);
+            let mut detour = map.find_path_avoiding(here, goal, &avoid);
+            if let Some(step) = detour.pop_front() {
+                if station.traffic.blocker(self.id, step, station.current_time).is_none() {
+                    station.traffic.stats.detours += 1;
+                    self.path_to_station = detour;
+                    self.step(map, station, step);
+                    return;
                 }
             }
         }
         
-        // Si on ne trouve pas de chemin, retourner un chemin vide
-        VecDeque::new()
+        // Interblocage face à face: le robot qui dispose d'une niche hors du trajet de l'autre
+        // s'y écarte; à égalité, le moins prioritaire (id le plus grand) cède le passage
+        if station.traffic.is_deadlock(here, blocker) {
+            let traffic = &station.traffic;
+            let refuge = match (traffic.side_refuge(map, self.id, here, blocker, next),
+                                traffic.side_refuge(map, blocker, next, self.id, here)) {
+                (Some(side), None) => Some(side),
+                (Some(side), Some(_)) if self.id > blocker => Some(side),
+                (None, None) if self.id > blocker || waited >= FORCED_YIELD_AFTER => traffic.retreat(map, self.id, here),
+                _ => None,
+            };
+            
+            if let Some(refuge) = refuge {
+                station.traffic.stats.deadlocks_resolved += 1;
+                self.path_to_station.clear();
+                self.step(map, station, refuge);
+            }
+        }
     }
     
-    // Heuristique pour A* (distance de Manhattan)
-    fn heuristic(&self, a: (usize, usize), b: (usize, usize)) -> usize {
-        let dx = (a.0 as isize - b.0 as isize).abs() as usize;
-        let dy = (a.1 as isize - b.1 as isize).abs() as usize;
-        dx + dy
+    // Déplacement effectif d'une case, enregistré auprès du contrôle de circulation
+    fn step(&mut self, map: &Map, station: &mut Station, next: (usize, usize)) {
+        let from = (self.x, self.y);
+        self.move_to(map, next.0, next.1);
+        self.stride -= 1.0;
+        self.health.wear_step();
+        station.traffic.moved(self.id, from, next);
+        station.traffic.reserve(self.id, &self.path_to_station, station.current_time);
     }
     
-    // Déplacement vers une position
-    fn move_to(&mut self, x: usize, y: usize) {
-        // Calculer la distance
-        let dx = (x as isize - self.x as isize).abs();
-        let dy = (y as isize - self.y as isize).abs();
-        let distance = dx.max(dy) as f32;
+    fn move_to(&mut self, map: &Map, x: usize, y: usize) {
+        // Calculer la distance selon la métrique de la carte (1.0 par pas orthogonal)
+        let distance = map.movement.step_cost((self.x, self.y), (x, y)) as f32 / STEP_COST as f32;
         
         // Consommer de l'énergie selon la distance et le type de robot
-        let energy_cost = match self.robot_type {
-            RobotType::Explorer => 0.3 * distance,
-            RobotType::EnergyCollector => 0.4 * distance,
-            RobotType::MineralCollector => 0.5 * distance,
-            RobotType::ScientificCollector => 0.6 * distance,
-        };
-        
-        self.energy -= energy_cost;
+        self.energy -= self.move_cost_factor() * distance;
         
         // Mettre à jour la position
         self.x = x;
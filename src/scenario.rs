@@ -0,0 +1,80 @@
+// src/scenario.rs
+// Scénario de mission chargé depuis un fichier JSON (voir `scenarios/`): graine de la carte et du
+// générateur aléatoire de la station, règles de déplacement, économie de la station, avant-postes
+// placés d'office et règle de résolution des désaccords entre observations. Les champs absents
+// gardent leur valeur par défaut.
+use crate::economy::Economy;
+use crate::audit::ResolutionPolicy;
+use crate::station::Station;
+use crate::map::{Map, MAIN_STATION};
+use crate::movement::MovementRules;
+use crate::wear::DEFAULT_SEED;
+use crate::outposts::MAX_OUTPOSTS;
+use crate::types::MAP_SIZE;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    pub seed: Option<u64>,              // Carte, défaillances et capteurs (carte tirée au hasard sinon)
+    pub movement: MovementRules,
+    pub economy: Economy,
+    pub outposts: Vec<(usize, usize)>,  // Positions des avant-postes au départ de la mission
+    pub merge_policy: ResolutionPolicy,
+}
+
+impl Scenario {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let scenario: Self = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    // Avant-postes sur la carte, distincts, hors de la station principale et en nombre autorisé
+    fn validate(&self) -> Result<(), String> {
+        if self.outposts.len() > MAX_OUTPOSTS {
+            return Err(format!("{} avant-postes pour {} au plus", self.outposts.len(), MAX_OUTPOSTS));
+        }
+        for (i, &(x, y)) in self.outposts.iter().enumerate() {
+            if x >= MAP_SIZE || y >= MAP_SIZE {
+                return Err(format!("avant-poste ({}, {}) hors de la carte", x, y));
+            }
+            if (x, y) == MAIN_STATION {
+                return Err(format!("avant-poste ({}, {}) sur la station principale", x, y));
+            }
+            if self.outposts[..i].contains(&(x, y)) {
+                return Err(format!("avant-poste ({}, {}) en double", x, y));
+            }
+        }
+        Ok(())
+    }
+
+    // Carte de la graine du scénario (aléatoire sans graine), soumise à ses règles de déplacement
+    pub fn map(&self) -> Map {
+        match self.seed {
+            Some(seed) => Map::generate(self.movement, seed),
+            None => Map::new_with_movement(self.movement),
+        }
+    }
+
+    // Station configurée par le scénario
+    pub fn station(&self) -> Station {
+        let mut station = Station::with_economy(self.seed.unwrap_or(DEFAULT_SEED), self.economy.clone());
+        station.set_merge_policy(self.merge_policy);
+        station
+    }
+
+    // Établit les avant-postes du scénario (rendus accessibles sur la carte)
+    pub fn place_outposts(&self, station: &mut Station, map: &mut Map) {
+        for &position in &self.outposts {
+            station.found_outpost(map, position, None);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+// src/scripting.rs
+// Pilotage des robots par des scripts Rhai, sans recompilation.
+//
+// Le script doit définir une fonction `decide(robot)` qui reçoit une vue en lecture seule
+// du robot et retourne une action: `idle()`, `explore()`, `collect()`, `return_home()`
+// ou `move_to(x, y)` (les chaînes "idle", "explore", "collect" et "return" sont aussi acceptées).
+use crate::types::{RobotType, RobotMode, MAP_SIZE};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::Station;
+use crate::events::SimulationEvent;
+use crate::inventory::CargoKind;
+use crate::behavior::{Action, Behavior, BehaviorRegistry, Observation, default_behavior};
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Scope, AST};
+use std::path::Path;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+// Nombre maximal d'opérations Rhai par décision (par tour)
+pub const DEFAULT_INSTRUCTION_BUDGET: u64 = 10_000;
+
+// Nom de fichier (sans extension) du script associé à un type de robot
+pub fn script_key(robot_type: RobotType) -> &'static str {
+    match robot_type {
+        RobotType::Explorer => "explorer",
+        RobotType::EnergyCollector => "energy_collector",
+        RobotType::MineralCollector => "mineral_collector",
+        RobotType::ScientificCollector => "scientific_collector",
+        RobotType::Relay => "relay",
+        RobotType::Repair => "repair",
+    }
+}
+
+fn mode_key(mode: RobotMode) -> &'static str {
+    match mode {
+        RobotMode::Exploring => "exploring",
+        RobotMode::Collecting => "collecting",
+        RobotMode::ReturnToStation => "returning",
+        RobotMode::Idle => "idle",
+        RobotMode::Stranded => "stranded",
+    }
+}
+
+// Moteur Rhai bridé: budget d'instructions, tailles limitées, aucune sortie
+fn sandboxed_engine(instruction_budget: u64) -> Engine {
+    let mut engine = Engine::new();
+
+    engine.set_max_operations(instruction_budget);
+    engine.set_max_call_levels(16);
+    engine.set_max_expr_depths(64, 32);
+    engine.set_max_string_size(1024);
+    engine.set_max_array_size(MAP_SIZE * (MAP_SIZE + 1)); // La mémoire est transmise en entier
+    engine.set_max_map_size(64);
+    engine.disable_symbol("eval");
+    engine.on_print(|_| {});
+    engine.on_debug(|_, _, _| {});
+
+    // Constructeurs d'actions accessibles au script
+    engine.register_type_with_name::<Action>("Action");
+    engine.register_fn("idle", || Action::Idle);
+    engine.register_fn("explore", || Action::Explore);
+    engine.register_fn("collect", || Action::Collect);
+    engine.register_fn("return_home", || Action::ReturnToStation);
+    engine.register_fn("move_to", |x: i64, y: i64| -> Result<Action, Box<EvalAltResult>> {
+        if x < 0 || y < 0 {
+            return Err(out_of_map(x, y).into());
+        }
+        Ok(Action::MoveTo((x as usize, y as usize)))
+    });
+
+    engine
+}
+
+fn out_of_map(x: i64, y: i64) -> String {
+    format!("move_to({}, {}) hors de la carte", x, y)
+}
+
+// Script compilé, partageable entre plusieurs robots
+#[derive(Clone)]
+pub struct ScriptProgram {
+    name: String,
+    engine: Arc<Engine>,
+    ast: Arc<AST>,
+    reported: Arc<Mutex<HashSet<String>>>, // Erreurs déjà journalisées, tous robots confondus
+}
+
+impl ScriptProgram {
+    // Compile un script et vérifie qu'il définit `decide(robot)`
+    pub fn compile(name: &str, source: &str, instruction_budget: u64) -> Result<Self, String> {
+        let engine = sandboxed_engine(instruction_budget);
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+
+        if !ast.iter_functions().any(|f| f.name == "decide" && f.params.len() == 1) {
+            return Err("le script doit définir une fonction decide(robot)".to_string());
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            engine: Arc::new(engine),
+            ast: Arc::new(ast),
+            reported: Arc::default(),
+        })
+    }
+
+    // Charge et compile un script depuis un fichier
+    pub fn from_file(path: &Path, instruction_budget: u64) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        Self::compile(&name, &source, instruction_budget)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Vrai la première fois qu'une erreur donnée se produit, pour ne la journaliser qu'une fois
+    fn first_report(&self, message: &str) -> bool {
+        self.reported.lock().unwrap().insert(message.to_string())
+    }
+
+    // Appelle `decide(robot)` et convertit le résultat en action
+    fn decide(&self, view: rhai::Map) -> Result<Action, String> {
+        let result = self.engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, "decide", (view,))
+            .map_err(|e| e.to_string())?;
+
+        let action = if result.is::<Action>() {
+            result.cast::<Action>()
+        } else if let Some(name) = result.clone().try_cast::<String>() {
+            match name.as_str() {
+                "idle" => Action::Idle,
+                "explore" => Action::Explore,
+                "collect" => Action::Collect,
+                "return" => Action::ReturnToStation,
+                other => return Err(format!("action inconnue '{}'", other)),
+            }
+        } else {
+            return Err(format!("decide doit retourner une action, pas {}", result.type_name()));
+        };
+
+        if let Action::MoveTo((x, y)) = action {
+            if x >= MAP_SIZE || y >= MAP_SIZE {
+                return Err(out_of_map(x as i64, y as i64));
+            }
+        }
+
+        Ok(action)
+    }
+}
+
+// Vue en lecture seule du robot transmise au script (copie, jamais de référence)
+fn robot_view(robot: &Robot, observation: &Observation) -> rhai::Map {
+    let mut view = rhai::Map::new();
+
+    view.insert("id".into(), (robot.id as i64).into());
+    view.insert("type".into(), script_key(robot.robot_type).into());
+    view.insert("mode".into(), mode_key(robot.mode).into());
+    view.insert("x".into(), (robot.x as i64).into());
+    view.insert("y".into(), (robot.y as i64).into());
+    view.insert("home_x".into(), (robot.home_station_x as i64).into());
+    view.insert("home_y".into(), (robot.home_station_y as i64).into());
+    view.insert("at_station".into(), observation.at_station.into());
+    view.insert("in_contact".into(), observation.in_contact.into());
+    view.insert("needs_return".into(), observation.needs_return.into());
+    view.insert("energy".into(), (robot.energy as f64).into());
+    view.insert("max_energy".into(), (robot.max_energy as f64).into());
+    view.insert("minerals".into(), (robot.inventory.amount(CargoKind::Mineral) as i64).into());
+    view.insert("scientific_data".into(), (robot.inventory.amount(CargoKind::ScientificData) as i64).into());
+    view.insert("energy_cells".into(), (robot.inventory.amount(CargoKind::EnergyCell) as i64).into());
+    view.insert("cargo_load".into(), (robot.inventory.load() as f64).into());
+    view.insert("motors".into(), (robot.health.motors as f64).into());
+    view.insert("sensors".into(), (robot.health.sensors as f64).into());
+    view.insert("battery".into(), (robot.health.battery as f64).into());
+    view.insert("tile".into(), format!("{:?}", observation.tile).to_lowercase().into());
+
+    let nearest = match observation.nearest_resource {
+        Some(((x, y), cost)) => {
+            let mut target = rhai::Map::new();
+            target.insert("x".into(), (x as i64).into());
+            target.insert("y".into(), (y as i64).into());
+            target.insert("cost".into(), (cost as i64).into());
+            Dynamic::from_map(target)
+        },
+        None => Dynamic::UNIT,
+    };
+    view.insert("nearest_resource".into(), nearest);
+
+    // Gisement attribué par la station (unit si aucun contrat)
+    let task = match observation.task {
+        Some((x, y)) => {
+            let mut task = rhai::Map::new();
+            task.insert("x".into(), (x as i64).into());
+            task.insert("y".into(), (y as i64).into());
+            Dynamic::from_map(task)
+        },
+        None => Dynamic::UNIT,
+    };
+    view.insert("task".into(), task);
+
+    // memory[y][x] vaut true si la case est connue du robot
+    let memory: Array = robot.memory.iter()
+        .map(|row| Dynamic::from_array(row.iter().map(|t| t.explored.into()).collect()))
+        .collect();
+    view.insert("memory".into(), memory.into());
+
+    view
+}
+
+// Comportement piloté par un script; en cas d'erreur, le robot suit son
+// comportement par défaut pour ce tour et la station journalise l'erreur la première
+// fois qu'elle se produit
+pub struct ScriptBehavior {
+    program: ScriptProgram,
+    fallback: Box<dyn Behavior>,
+    view: Option<rhai::Map>,
+    pending_error: Option<String>,
+}
+
+impl ScriptBehavior {
+    pub fn new(program: ScriptProgram, fallback: Box<dyn Behavior>) -> Self {
+        Self { program, fallback, view: None, pending_error: None }
+    }
+}
+
+impl Behavior for ScriptBehavior {
+    fn name(&self) -> &str {
+        self.program.name()
+    }
+
+    fn observe(&mut self, robot: &Robot, map: &Map, station: &Station) -> Observation {
+        let observation = self.fallback.observe(robot, map, station);
+        self.view = Some(robot_view(robot, &observation));
+        observation
+    }
+
+    fn decide(&mut self, observation: &Observation) -> Action {
+        let view = self.view.take().unwrap_or_default();
+
+        match self.program.decide(view) {
+            Ok(action) => action,
+            Err(message) => {
+                if self.program.first_report(&message) {
+                    self.pending_error = Some(message);
+                }
+                self.fallback.decide(observation)
+            }
+        }
+    }
+
+    fn act(&mut self, robot: &mut Robot, action: Action, map: &mut Map, station: &mut Station) {
+        if let Some(message) = self.pending_error.take() {
+            station.log_event(SimulationEvent::ScriptError {
+                robot_id: robot.id,
+                script: self.program.name().to_string(),
+                message,
+            });
+        }
+
+        robot.perform(action, map, station);
+    }
+}
+
+// Enregistre un script comme comportement d'un type de robot
+pub fn register_script(registry: &mut BehaviorRegistry, robot_type: RobotType, program: ScriptProgram) {
+    registry.register(robot_type, move || {
+        Box::new(ScriptBehavior::new(program.clone(), default_behavior(robot_type)))
+    });
+}
+
+// Charge les scripts `<type>.rhai` présents dans un dossier (ex: `explorer.rhai`).
+// Retourne les types pilotés par script et les erreurs de compilation rencontrées.
+pub fn load_script_dir(registry: &mut BehaviorRegistry, dir: &Path) -> (Vec<RobotType>, Vec<String>) {
+    let mut loaded = Vec::new();
+    let mut errors = Vec::new();
+
+    for robot_type in [RobotType::Explorer, RobotType::EnergyCollector,
+                       RobotType::MineralCollector, RobotType::ScientificCollector, RobotType::Relay,
+                       RobotType::Repair] {
+        let path = dir.join(format!("{}.rhai", script_key(robot_type)));
+        if !path.exists() {
+            continue;
+        }
+
+        match ScriptProgram::from_file(&path, DEFAULT_INSTRUCTION_BUDGET) {
+            Ok(program) => {
+                register_script(registry, robot_type, program);
+                loaded.push(robot_type);
+            },
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    (loaded, errors)
+}
@@ -0,0 +1,77 @@
+// src/sensors.rs
+// Modèle de capteurs bruités (optionnel): plus une case est loin, plus le robot risque de se
+// tromper sur sa nature (les cases masquées par les obstacles sont exclues en amont par le
+// champ de vision, voir `fov`). Chaque observation porte une confiance, cumulée dans la mémoire
+// du robot par les observations concordantes et affaiblie par les observations contradictoires.
+use crate::types::TileType;
+use crate::map::Map;
+use crate::station::TerrainData;
+use rand::Rng;
+
+// Probabilité d'erreur de classification par case de distance
+pub const DEFAULT_ERROR_PER_TILE: f32 = 0.05;
+// Probabilité d'erreur maximale, quelle que soit la distance
+pub const MAX_ERROR: f32 = 0.45;
+
+const TILE_KINDS: [TileType; 5] = [
+    TileType::Empty,
+    TileType::Obstacle,
+    TileType::Energy,
+    TileType::Mineral,
+    TileType::Scientific,
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensorModel {
+    pub error_per_tile: f32,
+}
+
+impl Default for SensorModel {
+    fn default() -> Self {
+        Self { error_per_tile: DEFAULT_ERROR_PER_TILE }
+    }
+}
+
+impl SensorModel {
+    // Probabilité de mal classer une case à une distance donnée
+    pub fn error_rate(&self, distance: usize) -> f32 {
+        (self.error_per_tile * distance as f32).min(MAX_ERROR)
+    }
+
+    // Nature perçue d'une case et confiance de l'observation
+    pub fn observe<R: Rng>(&self, map: &Map, from: (usize, usize), to: (usize, usize), rng: &mut R) -> (TileType, f32) {
+        let distance = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1));
+        let error = self.error_rate(distance);
+        let actual = map.get_tile(to.0, to.1);
+        let tile = if rng.gen::<f32>() < error {
+            // Confondre la case avec une autre nature, au hasard
+            let others: Vec<&TileType> = TILE_KINDS.iter().filter(|&t| *t != actual).collect();
+            others[rng.gen_range(0..others.len())].clone()
+        } else {
+            actual
+        };
+        (tile, 1.0 - error)
+    }
+}
+
+// Cumul d'une observation avec ce que le robot sait déjà de la case (mise à jour bayésienne de
+// la probabilité que la nature connue soit la bonne): une observation concordante renforce la
+// confiance, une observation contradictoire l'affaiblit et remplace la nature connue quand elle
+// devient la plus probable. Une observation certaine remplace toujours la précédente.
+pub fn accumulate(known: &TerrainData, tile: TileType, confidence: f32) -> (TileType, f32) {
+    if !known.explored || confidence >= 1.0 {
+        return (tile, confidence);
+    }
+
+    let p = known.confidence;
+    if known.tile == tile {
+        (tile, p * confidence / (p * confidence + (1.0 - p) * (1.0 - confidence)))
+    } else {
+        let kept = p * (1.0 - confidence) / (p * (1.0 - confidence) + (1.0 - p) * confidence);
+        if kept >= 0.5 {
+            (known.tile.clone(), kept)
+        } else {
+            (tile, 1.0 - kept)
+        }
+    }
+}
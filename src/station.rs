@@ -1,15 +1,37 @@
-use crate::types::{TileType, RobotType, MAP_SIZE};
+use crate::types::{TileType, RobotType, RobotMode, MAP_SIZE};
 use crate::map::Map;
 use crate::robot::Robot;
+use crate::inventory::CargoKind;
+use crate::economy::{Economy, ResourceTrend};
+use crate::planner::{BalancedPlanner, BuildDecision, FleetPlanner, FleetView};
+use crate::construction::{BuildOrigin, BuildQueue};
+use crate::behavior::BehaviorRegistry;
+use crate::events::{EventLog, SimulationEvent};
+use crate::knowledge::{KnowledgeStats, PeerExchange};
+use crate::audit::{merge_audited, AuditLog, ConflictQuery, ResolutionPolicy};
+use crate::crdt::{Replica, MAIN_REPLICA};
+use crate::comms::CommNetwork;
+use crate::traffic::TrafficControl;
+use crate::tasks::TaskMarket;
+use crate::rescue::RescueService;
+use crate::wear::{Health, MaintenanceService, BATTERY_REPLACEMENT_COST, DEFAULT_SEED, REPAIR_THRESHOLD, STATION_REPAIR_RATE};
+use crate::sensors::SensorModel;
+use crate::research::Research;
+use crate::docking::Docking;
+use crate::outposts::{Outposts, OUTPOST_MINERALS, REPLICATION_INTERVAL};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::collections::HashMap;
 
 // Structure pour représenter une donnée de terrain avec timestamp
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TerrainData {
     pub explored: bool,
     pub timestamp: u32,     // Quand la case a été explorée
     pub robot_id: usize,    // Quel robot a exploré cette case
     pub robot_type: RobotType, // Type du robot qui a exploré cette case
+    pub tile: TileType,     // Nature de la case observée
+    pub confidence: f32,    // Confiance dans cette nature (1.0 avec des capteurs parfaits)
 }
 
 pub struct Station {
@@ -17,13 +39,54 @@ pub struct Station {
     pub collected_minerals: u32,
     pub collected_scientific_data: u32,
     pub global_memory: Vec<Vec<TerrainData>>, // Mémoire partagée globale
-    pub conflict_count: usize,  // Nombre de conflits résolus
+    pub replica: Replica,       // Réplication de la mémoire globale (points des cases, horloge vectorielle)
+    pub conflict_count: usize,  // Nombre de désaccords résolus entre observations
     pub next_robot_id: usize,   // ID pour le prochain robot créé
     pub current_time: u32,      // Horloge globale de la simulation
+    pub frontier_claims: HashMap<usize, (usize, usize)>, // Frontière visée par chaque robot
+    pub behaviors: BehaviorRegistry, // Comportement attribué à chaque type de robot créé
+    pub events: EventLog,            // Derniers événements notables, transmis à la Terre
+    pub peer_exchange: PeerExchange, // Paramètres radio des échanges entre robots
+    pub knowledge_stats: KnowledgeStats, // Propagation des connaissances (station / entre robots)
+    pub comms: CommNetwork,          // Couverture radio de la station et de ses relais
+    pub traffic: TrafficControl,     // Occupation des cases et réservations de trajets
+    pub market: TaskMarket,          // Contrats de collecte attribués aux enchères
+    pub rescue: RescueService,       // Robots en panne et missions de secours
+    pub maintenance: MaintenanceService, // Réparations sur le terrain et statistiques d'usure
+    pub rng: StdRng,                 // Générateur aléatoire de la simulation (défaillances, capteurs)
+    pub sensors: Option<SensorModel>, // Capteurs bruités des robots (parfaits si absent)
+    pub economy: Economy,            // Coûts de construction, conversions et entretien
+    pub construction: BuildQueue,    // Robots commandés, construits dans l'ordre
+    pub planner: Box<dyn FleetPlanner>, // Choix des robots commandés par la station
+    pub last_plan: Option<BuildDecision>, // Dernier choix du planificateur et sa justification
+    pub trend: ResourceTrend,        // Évolution des réserves (recettes et dépenses courantes)
+    pub research: Research,          // Technologies financées par les données scientifiques
+    pub outposts: Outposts,          // Stations secondaires (quais, réserves et connaissances propres)
+    pub docking: Docking,            // Baies de recharge occupées et files d'attente des quais
+    pub merge_policy: ResolutionPolicy, // Règle de résolution des désaccords entre observations
+    pub audit: AuditLog,             // Désaccords résolus lors des fusions à la station
+    pub conflict_query: ConflictQuery, // Conflits du journal transmis à la Terre
+    upkeep_due: f32,                 // Entretien accumulé, pas encore prélevé
+}
+
+impl Default for Station {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Station {
     pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+    
+    // Station dont le générateur aléatoire part d'une graine donnée (simulation reproductible)
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_economy(seed, Economy::default())
+    }
+    
+    // Station configurée par un scénario: graine et économie
+    pub fn with_economy(seed: u64, economy: Economy) -> Self {
         // Initialiser la mémoire globale avec des données non explorées
         let mut global_memory = Vec::with_capacity(MAP_SIZE);
         for _ in 0..MAP_SIZE {
@@ -33,6 +96,8 @@ impl Station {
                     timestamp: 0,
                     robot_id: 0,
                     robot_type: RobotType::Explorer,
+                    tile: TileType::Empty,
+                    confidence: 0.0,
                 }; 
                 MAP_SIZE
             ];
@@ -40,162 +105,468 @@ impl Station {
         }
         
         Self {
-            energy_reserves: 100,
-            collected_minerals: 0,
+            energy_reserves: economy.starting_energy,
+            collected_minerals: economy.starting_minerals,
             collected_scientific_data: 0,
             global_memory,
+            replica: Replica::new(MAIN_REPLICA),
             conflict_count: 0,
             next_robot_id: 1,  // Les ID commencent à 1
             current_time: 0,
+            frontier_claims: HashMap::new(),
+            behaviors: BehaviorRegistry::default(),
+            events: EventLog::new(),
+            peer_exchange: PeerExchange::default(),
+            knowledge_stats: KnowledgeStats::default(),
+            comms: CommNetwork::default(),
+            traffic: TrafficControl::new(),
+            market: TaskMarket::new(),
+            rescue: RescueService::default(),
+            maintenance: MaintenanceService::default(),
+            rng: StdRng::seed_from_u64(seed),
+            sensors: None,
+            economy,
+            construction: BuildQueue::default(),
+            planner: Box::new(BalancedPlanner),
+            last_plan: None,
+            trend: ResourceTrend::default(),
+            research: Research::default(),
+            outposts: Outposts::default(),
+            docking: Docking::default(),
+            merge_policy: ResolutionPolicy::default(),
+            audit: AuditLog::default(),
+            conflict_query: ConflictQuery::default(),
+            upkeep_due: 0.0,
         }
     }
     
     // Incrémente l'horloge globale
     pub fn tick(&mut self) {
         self.current_time += 1;
+        self.trend.close_tick();
+    }
+    
+    // Entretien de la flotte: chaque robot consomme une part des réserves d'énergie à chaque tour
+    pub fn pay_upkeep(&mut self, robot_count: usize) {
+        self.upkeep_due += self.economy.upkeep_per_robot * robot_count as f32;
+        let due = self.upkeep_due.floor();
+        self.upkeep_due -= due;
+        self.trend.record(-(due as i64), 0);
+        self.energy_reserves = self.energy_reserves.saturating_sub(due as u32);
     }
     
-    // Partage des connaissances entre un robot et la station (façon git)
+    // Journalise un événement à l'heure actuelle de la station
+    pub fn log_event(&mut self, event: SimulationEvent) {
+        self.events.push(self.current_time, event);
+    }
+    
+    // Partage des connaissances entre un robot et la station à laquelle il est amarré, limité aux
+    // nouveautés de part et d'autre: le robot ne reçoit que les cases dont il n'a pas encore vu la
+    // version (selon son horloge vectorielle) et ne transmet que celles apprises depuis sa dernière
+    // synchronisation. Ses observations sont fusionnées dans la réplique de la station; les
+    // désaccords sur la nature d'une case sont tranchés par la règle et consignés dans le journal
     pub fn share_knowledge(&mut self, robot: &mut Robot) {
         // Uniquement si le robot est à la station
         if robot.x == robot.home_station_x && robot.y == robot.home_station_y {
-            let mut conflicts = 0;
+            let mut conflicts = Vec::new();
+            let mut rejected = Vec::new();
+            let policy = self.merge_policy;
             
-            // Pour chaque case de la carte
-            for y in 0..MAP_SIZE {
-                for x in 0..MAP_SIZE {
-                    // Si le robot a exploré cette case
-                    if robot.memory[y][x].explored {
-                        // Vérifier si la station a déjà des données sur cette case
-                        if self.global_memory[y][x].explored {
-                            // CONFLIT: La case est connue par la station et le robot
-                            // Résoudre en fonction des timestamps
-                            if robot.memory[y][x].timestamp > self.global_memory[y][x].timestamp {
-                                // Les données du robot sont plus récentes -> Mettre à jour la station
-                                self.global_memory[y][x] = robot.memory[y][x].clone();
-                                conflicts += 1;
-                            }
-                            // Sinon, garder les données de la station (plus récentes)
-                        } else {
-                            // Pas de conflit, ajouter les connaissances du robot
-                            self.global_memory[y][x] = robot.memory[y][x].clone();
-                        }
-                    }
+            // Un robot rattaché à un avant-poste partage avec les connaissances de celui-ci
+            let (memory, replica) = match self.outposts.at_mut((robot.x, robot.y)) {
+                Some(outpost) => (&mut outpost.memory, &mut outpost.replica),
+                None => (&mut self.global_memory, &mut self.replica),
+            };
+            
+            // Le robot récupère les nouveautés de la station
+            let mut updated = replica.delta(memory, &robot.clock).merge_into(&mut robot.memory, &mut robot.clock, policy);
+            
+            // Ses propres nouveautés, tranchées par la règle, reçoivent un point de la station
+            for (x, y) in robot.changes.take() {
+                let report = &robot.memory[y][x];
+                let (changed, conflict) = merge_audited(&mut memory[y][x], report, policy, (x, y), self.current_time);
+                if changed {
+                    replica.stamp((x, y));
+                    updated += 1;
+                } else if report.explored && memory[y][x] != *report {
+                    rejected.push((x, y));
                 }
+                conflicts.extend(conflict);
             }
             
-            // Le robot récupère toutes les connaissances de la station
-            for y in 0..MAP_SIZE {
-                for x in 0..MAP_SIZE {
-                    if self.global_memory[y][x].explored {
-                        robot.memory[y][x] = self.global_memory[y][x].clone();
-                    }
-                }
+            // Le robot repart avec la version retenue des cases où la sienne a été écartée
+            for (x, y) in rejected {
+                robot.memory[y][x] = memory[y][x].clone();
+                updated += 1;
             }
             
-            // Mettre à jour les statistiques
-            self.conflict_count += conflicts;
+            // Le robot détient désormais la version retenue de chaque case: son horloge couvre aussi
+            // les points de ses propres envois, qui ne lui seront donc pas renvoyés
+            robot.clock.merge(&replica.clock);
             
-            println!("Robot {} a partagé ses connaissances. Conflits résolus: {}", robot.id, conflicts);
+            // Mettre à jour les statistiques et le journal d'audit
+            self.knowledge_stats.tiles_via_station += updated;
+            for conflict in conflicts {
+                if self.audit.push(conflict) {
+                    self.conflict_count += 1;
+                }
+            }
         }
     }
     
-    // Vérifie si la station peut créer un nouveau robot et le fait si possible
-    pub fn try_create_robot(&mut self, map: &Map) -> Option<Robot> {
-        // Coûts de création d'un robot
-        let energy_cost = 50;
-        let mineral_cost = 15;
+    // Échanges de connaissances entre les robots à portée radio les uns des autres
+    pub fn exchange_peer_knowledge(&mut self, robots: &mut [Robot]) {
+        self.peer_exchange.exchange(robots, self.merge_policy, &mut self.knowledge_stats);
+    }
+    
+    // Recale l'occupation des cases sur la position des robots avant leurs déplacements
+    pub fn update_traffic(&mut self, robots: &[Robot]) {
+        self.traffic.sync(robots, self.current_time);
+        self.docking.sync(robots, self.current_time);
+    }
+    
+    // Met à jour la couverture radio; les robots en contact transmettent leurs observations en direct
+    pub fn update_communications(&mut self, map: &Map, robots: &mut [Robot]) {
+        self.comms.update((map.station_x, map.station_y), robots);
         
-        // Vérifier si on a assez de ressources
-        if self.energy_reserves >= energy_cost && self.collected_minerals >= mineral_cost {
-            // Déterminer le type de robot à créer
-            let robot_type = self.determine_needed_robot_type(map);
-            
-            // Consommer les ressources
-            self.energy_reserves -= energy_cost;
-            self.collected_minerals -= mineral_cost;
-            
-            // Créer et retourner le robot
-            let new_robot_id = self.next_robot_id;
-            self.next_robot_id += 1;
+        let mut updated = 0;
+        for robot in robots.iter_mut() {
+            robot.in_contact = self.comms.is_connected(robot.id);
             
-            println!("Station: Création d'un nouveau robot #{} de type {:?}", new_robot_id, robot_type);
-            
-            // Initialiser la mémoire du robot avec les connaissances de la station
-            let memory = self.global_memory.clone();
+            // À la station, le robot se synchronise déjà en s'y amarrant; en panne, il n'émet plus
+            if !robot.in_contact || robot.is_at_station() || robot.mode == RobotMode::Stranded {
+                continue;
+            }
             
-            return Some(Robot::new_with_memory(
-                map.station_x, 
-                map.station_y, 
-                robot_type, 
-                new_robot_id,
-                map.station_x, 
-                map.station_y,
-                memory
-            ));
+            // Seules les cases apprises depuis la dernière transmission partent; la station répond
+            // avec sa version des cases où celle du robot a été écartée
+            for (x, y) in robot.changes.take() {
+                let (changed, conflict) = merge_audited(&mut self.global_memory[y][x], &robot.memory[y][x],
+                                                        self.merge_policy, (x, y), self.current_time);
+                // Le robot connaît déjà la version retenue: il en note le point pour ne pas la recevoir
+                // de nouveau à son retour
+                if changed {
+                    robot.clock.add(self.replica.stamp((x, y)));
+                    updated += 1;
+                } else if robot.memory[y][x].explored {
+                    robot.memory[y][x] = self.global_memory[y][x].clone();
+                    if let Some(dot) = self.replica.dot((x, y)) {
+                        robot.clock.add(dot);
+                    }
+                }
+                if conflict.is_some_and(|conflict| self.audit.push(conflict)) {
+                    self.conflict_count += 1;
+                }
+            }
         }
         
-        None
+        self.knowledge_stats.tiles_via_radio += updated;
     }
     
-    // Détermine le type de robot le plus nécessaire actuellement
-    fn determine_needed_robot_type(&self, map: &Map) -> RobotType {
-        // Compter le nombre de ressources restantes sur la carte
-        let mut energy_count = 0;
-        let mut mineral_count = 0;
-        let mut scientific_count = 0;
+    // Publie les gisements connus et attribue les collectes aux enchères
+    pub fn allocate_tasks(&mut self, map: &Map, robots: &mut [Robot]) {
+        self.market.run(&self.global_memory, map, robots, self.current_time);
+    }
+    
+    // Immobilise les robots à court d'énergie, organise les secours et abandonne ceux
+    // qui n'ont pas pu être secourus à temps; envoie les réparateurs auprès des robots usés
+    pub fn handle_failures(&mut self, map: &Map, robots: &mut Vec<Robot>) {
+        let mut events = self.rescue.run(map, robots, self.current_time, self.economy.cell_energy);
+        events.extend(self.maintenance.run(map, robots));
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                match map.get_tile(x, y) {
-                    TileType::Energy => energy_count += 1,
-                    TileType::Mineral => mineral_count += 1,
-                    TileType::Scientific => scientific_count += 1,
-                    _ => {}
+        for robot in robots.iter() {
+            if robot.mode == RobotMode::Stranded {
+                self.release_frontier(robot.id);
+            }
+        }
+        for event in events {
+            self.log_event(event);
+        }
+    }
+    
+    // Réserve une frontière pour un robot (remplace sa réservation précédente)
+    pub fn claim_frontier(&mut self, robot_id: usize, target: (usize, usize)) {
+        self.frontier_claims.insert(robot_id, target);
+    }
+    
+    // Libère la frontière réservée par un robot
+    pub fn release_frontier(&mut self, robot_id: usize) {
+        self.frontier_claims.remove(&robot_id);
+    }
+    
+    // Vérifie si un autre robot vise déjà une frontière proche de cette cible
+    pub fn is_frontier_claimed(&self, target: (usize, usize), robot_id: usize, radius: usize) -> bool {
+        self.frontier_claims.iter().any(|(&id, &claimed)| {
+            id != robot_id
+                && claimed.0.abs_diff(target.0) <= radius
+                && claimed.1.abs_diff(target.1) <= radius
+        })
+    }
+    
+    // Fonde un avant-poste; ses connaissances et leur réplique partent de celles de la station principale
+    pub fn found_outpost(&mut self, map: &mut Map, position: (usize, usize), founder: Option<usize>) -> usize {
+        map.connect_to_station(position.0, position.1);
+        let outpost_id = self.outposts.found(position, self.global_memory.clone(), &self.replica, founder);
+        self.log_event(SimulationEvent::OutpostFounded { outpost_id, position, founder });
+        outpost_id
+    }
+    
+    // Avant-postes: fondation sur le terrain, rattachement des robots à la station la plus
+    // proche et réplication périodique vers la station principale
+    pub fn update_outposts(&mut self, map: &mut Map, robots: &mut [Robot]) {
+        let main = (map.station_x, map.station_y);
+        
+        if let Some(founder) = robots.iter_mut().find(|r| self.outposts.can_found(map, r, main)) {
+            founder.inventory.take(CargoKind::Mineral, OUTPOST_MINERALS);
+            let (position, founder_id) = ((founder.x, founder.y), founder.id);
+            self.found_outpost(map, position, Some(founder_id));
+        }
+        
+        // Un robot en panne, en mission de secours ou en recharge garde sa station; les avant-postes
+        // aux réserves épuisées ne peuvent plus recharger et n'accueillent plus que les robots qui
+        // leur rapportent de l'énergie
+        if !self.outposts.sites.is_empty() {
+            for robot in robots.iter_mut().filter(|r| r.mode != RobotMode::Stranded && r.rescue.is_none() && !r.charging) {
+                let brings_energy = robot.inventory.amount(CargoKind::EnergyCell) > 0
+                    || robot.inventory.amount(CargoKind::Mineral) > 0;
+                let min_energy = if brings_energy { 0 } else { self.economy.charge_rate };
+                if let Some((dock, _)) = self.outposts.nearest_supplied_dock(map, (robot.x, robot.y), main, min_energy) {
+                    (robot.home_station_x, robot.home_station_y) = dock;
                 }
             }
         }
         
-        // Si nous avons très peu d'énergie, priorité à un collecteur d'énergie
-        if energy_count > 0 && (energy_count <= 3 || self.energy_reserves < 100) {
-            return RobotType::EnergyCollector;
+        if self.current_time.is_multiple_of(REPLICATION_INTERVAL) {
+            let replication = self.outposts.replicate(&mut self.global_memory, &mut self.replica, self.merge_policy);
+            self.collected_minerals += replication.minerals;
+            self.collected_scientific_data += replication.scientific_data;
+            self.knowledge_stats.tiles_via_replication += replication.tiles;
+        }
+    }
+    
+    // Change la règle de résolution des désaccords; les avant-postes et la station principale
+    // réévaluent alors toutes leurs cases pour continuer à converger
+    pub fn set_merge_policy(&mut self, policy: ResolutionPolicy) {
+        if policy == self.merge_policy {
+            return;
+        }
+        self.merge_policy = policy;
+        let tiles = self.outposts.resync(&mut self.global_memory, &mut self.replica, policy);
+        self.knowledge_stats.tiles_via_replication += tiles;
+    }
+    
+    // Investit les données scientifiques dans la recherche; un niveau acquis équipe aussitôt
+    // tous les robots en service
+    pub fn update_research(&mut self, robots: &mut [Robot]) {
+        if let Some(technology) = self.research.fund(&mut self.collected_scientific_data) {
+            let upgrades = self.research.upgrades;
+            for robot in robots.iter_mut() {
+                robot.apply_upgrades(upgrades);
+            }
+            self.log_event(SimulationEvent::ResearchCompleted { technology, level: upgrades.level(technology) });
+        }
+    }
+    
+    // Commande la construction d'un robot; retourne l'identifiant de la commande
+    pub fn enqueue_build(&mut self, robot_type: RobotType, origin: BuildOrigin) -> usize {
+        let cost = self.economy.build_cost(robot_type).clone();
+        self.construction.push(robot_type, origin, cost)
+    }
+    
+    // Annule une commande; les ressources déjà réservées sont restituées
+    pub fn cancel_build(&mut self, id: usize) -> bool {
+        match self.construction.remove(id) {
+            Some(order) => {
+                if order.reserved {
+                    self.energy_reserves += order.cost.energy;
+                    self.collected_minerals += order.cost.minerals;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+    
+    // Fait avancer la file de construction d'un tour et retourne le robot terminé, s'il y en a un.
+    // La station commande elle-même un robot quand la file est vide; tant que le coût de sa
+    // commande n'est pas réservé, le type suit l'évolution des besoins
+    pub fn advance_construction(&mut self, map: &Map, robots: &[Robot]) -> Option<Robot> {
+        if self.construction.is_empty() {
+            let needed = self.determine_needed_robot_type(robots);
+            self.enqueue_build(needed, BuildOrigin::Planner);
+        } else if self.construction.head().is_some_and(|o| o.origin == BuildOrigin::Planner && !o.reserved) {
+            let needed = self.determine_needed_robot_type(robots);
+            let cost = self.economy.build_cost(needed).clone();
+            if let Some(order) = self.construction.head_mut() {
+                order.robot_type = needed;
+                order.cost = cost;
+            }
+        }
+        
+        let order = self.construction.head_mut()?;
+        if !order.reserved {
+            
+            // Réserver le coût dès que les réserves le permettent
+            if self.energy_reserves < order.cost.energy || self.collected_minerals < order.cost.minerals {
+                return None;
+            }
+            self.energy_reserves -= order.cost.energy;
+            self.collected_minerals -= order.cost.minerals;
+            order.reserved = true;
         }
         
-        // Si nous avons peu de minerais mais assez d'énergie, créer un collecteur de minerais
-        if mineral_count > 0 && (mineral_count <= 5 || self.collected_minerals < 30) {
-            return RobotType::MineralCollector;
+        order.progress += 1;
+        if !order.is_complete() {
+            return None;
         }
+        let order_id = order.id;
+        let robot_type = order.robot_type;
+        self.construction.remove(order_id);
+        
+        // Créer et retourner le robot
+        let new_robot_id = self.next_robot_id;
+        self.next_robot_id += 1;
         
-        // Si nous avons des points d'intérêt scientifique et assez de ressources
-        if scientific_count > 0 && self.energy_reserves >= 100 {
-            return RobotType::ScientificCollector;
+        println!("Station: Création d'un nouveau robot #{} de type {:?}", new_robot_id, robot_type);
+        self.log_event(SimulationEvent::RobotBuilt { robot_id: new_robot_id, robot_type });
+        
+        // Le robot quitte le chantier avec les connaissances de la station et son horloge, pour ne
+        // pas partir explorer une carte vide faute de place à quai
+        let mut robot = Robot::new(map.station_x, map.station_y, robot_type);
+        robot.id = new_robot_id;
+        self.knowledge_stats.tiles_via_station += self.replica.delta(&self.global_memory, &robot.clock)
+            .merge_into(&mut robot.memory, &mut robot.clock, self.merge_policy);
+        robot.set_behavior(self.behaviors.create(robot_type));
+        robot.apply_upgrades(self.research.upgrades);
+        
+        Some(robot)
+    }
+    
+    // Consulte le planificateur de flotte avec les connaissances de la station
+    fn determine_needed_robot_type(&mut self, robots: &[Robot]) -> RobotType {
+        let mut composition = HashMap::new();
+        for robot in robots {
+            *composition.entry(robot.robot_type).or_insert(0) += 1;
         }
         
-        // Par défaut, si la carte n'est pas bien explorée ou s'il reste peu de ressources,
-        // créer un robot explorateur
-        RobotType::Explorer
+        let view = FleetView {
+            knowledge: &self.global_memory,
+            composition,
+            unexplored: 1.0 - self.get_exploration_percentage() / 100.0,
+            energy_reserves: self.energy_reserves,
+            collected_minerals: self.collected_minerals,
+            energy_trend: self.trend.energy_rate(),
+            mineral_trend: self.trend.mineral_rate(),
+            out_of_contact: self.comms.out_of_contact().len(),
+            relay_count: self.comms.relay_count(),
+            needs_repairer: self.maintenance.needs_repairer(),
+        };
+        let decision = self.planner.plan(&view);
+        let robot_type = decision.robot_type;
+        self.last_plan = Some(decision);
+        robot_type
+    }
+    
+    // Révision d'un robot à quai: moteurs et capteurs remis en état progressivement, batterie
+    // remplacée contre des minerais quand sa capacité est trop dégradée
+    pub fn maintain(&mut self, health: &mut Health) {
+        if health.repair(STATION_REPAIR_RATE) > 0.0 && health.is_repaired() {
+            self.maintenance.stats.station_repairs += 1;
+        }
+        if health.battery < REPAIR_THRESHOLD && self.collected_minerals >= BATTERY_REPLACEMENT_COST {
+            self.collected_minerals -= BATTERY_REPLACEMENT_COST;
+            self.trend.record(0, -(BATTERY_REPLACEMENT_COST as i64));
+            health.replace_battery();
+            self.maintenance.stats.batteries_replaced += 1;
+        }
     }
     
-    // Déposer des ressources à la station
-    pub fn deposit_resources(&mut self, minerals: u32, scientific_data: u32) {
-        self.collected_minerals += minerals;
-        self.collected_scientific_data += scientific_data;
-        self.energy_reserves += minerals; // Convertir des minerais en énergie
+    // Demande d'une baie au quai `dock`. Quand ses réserves ne suffisent plus à recharger, les
+    // robots qui rapportent de l'énergie passent en tête de file
+    pub fn request_dock(&mut self, robot: &Robot, dock: (usize, usize)) -> bool {
+        let reserves = self.outposts.at(dock).map_or(self.energy_reserves, |o| o.energy_reserves);
+        let brings_energy = robot.inventory.amount(CargoKind::EnergyCell) > 0
+            || robot.inventory.amount(CargoKind::Mineral) > 0;
+        let priority = brings_energy && reserves < self.economy.charge_rate;
+        self.docking.request(robot.id, dock, self.economy.docking_bays, priority, self.current_time)
+    }
+    
+    // Énergie délivrée à un robot amarré au quai `dock` pendant ce tour: au plus le débit d'une
+    // baie, prise sur les réserves du quai
+    pub fn draw_charge(&mut self, dock: (usize, usize), wanted: f32) -> f32 {
+        let reserves = match self.outposts.at_mut(dock) {
+            Some(outpost) => &mut outpost.energy_reserves,
+            None => &mut self.energy_reserves,
+        };
+        let units = (wanted.max(0.0) as u32).min(self.economy.charge_rate).min(*reserves);
+        *reserves -= units;
+        self.trend.record(-(units as i64), 0);
+        self.docking.stats.energy_delivered += units;
+        units as f32
+    }
+    
+    // Déposer le contenu d'un emplacement de soute au quai d'une position donnée: dans les
+    // réserves de l'avant-poste s'il y en a un, sinon dans celles de la station principale
+    pub fn deposit_at(&mut self, position: (usize, usize), kind: CargoKind, amount: u32) {
+        let Some(outpost) = self.outposts.at_mut(position) else {
+            return self.deposit(kind, amount);
+        };
+        match kind {
+            CargoKind::Mineral => {
+                let energy = amount * self.economy.mineral_energy;
+                outpost.collected_minerals += amount;
+                outpost.energy_reserves += energy;
+                self.trend.record(energy as i64, amount as i64);
+            },
+            CargoKind::ScientificData => outpost.collected_scientific_data += amount,
+            CargoKind::EnergyCell => {
+                let energy = amount * self.economy.cell_energy;
+                outpost.energy_reserves += energy;
+                self.trend.record(energy as i64, 0);
+            },
+        }
+    }
+    
+    // Déposer le contenu d'un emplacement de soute à la station
+    pub fn deposit(&mut self, kind: CargoKind, amount: u32) {
+        match kind {
+            CargoKind::Mineral => {
+                let energy = amount * self.economy.mineral_energy; // Convertir des minerais en énergie
+                self.collected_minerals += amount;
+                self.energy_reserves += energy;
+                self.trend.record(energy as i64, amount as i64);
+            },
+            CargoKind::ScientificData => self.collected_scientific_data += amount,
+            CargoKind::EnergyCell => {
+                let energy = amount * self.economy.cell_energy;
+                self.energy_reserves += energy;
+                self.trend.record(energy as i64, 0);
+            },
+        }
     }
     
     // Méthode pour évaluer les besoins actuels de la station
     pub fn get_status(&self) -> String {
         let status = match (self.energy_reserves, self.collected_minerals) {
-            (e, m) if e < 30 => "Faible en énergie",
-            (e, m) if m < 10 => "Faible en minerais",
+            (e, _) if e < 30 => "Faible en énergie",
+            (_, m) if m < 10 => "Faible en minerais",
             (e, m) if e >= 200 && m >= 50 => "Ressources abondantes",
             _ => "Ressources adéquates",
         };
         
-        format!("{} | Création robot: {}/{} énergie, {}/{} minerai | Conflits: {}", 
+        // Progression de la construction en cours, ou vers le coût de la prochaine commande
+        let construction = match self.construction.head() {
+            Some(order) if order.reserved => format!("Construction {:?}: {}/{} tours",
+                order.robot_type, order.progress, order.cost.build_time),
+            Some(order) => format!("Création {:?}: {}/{} énergie, {}/{} minerai",
+                order.robot_type,
+                self.energy_reserves.min(order.cost.energy), order.cost.energy,
+                self.collected_minerals.min(order.cost.minerals), order.cost.minerals),
+            None => "Aucune construction".to_string(),
+        };
+        format!("{} | {} | Conflits: {}", 
                 status, 
-                self.energy_reserves.min(50), 50,  // Afficher la progression vers l'énergie nécessaire
-                self.collected_minerals.min(15), 15,  // Afficher la progression vers les minerais nécessaires
+                construction,
                 self.conflict_count)  // Afficher les conflits résolus
     }
     
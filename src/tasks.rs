@@ -0,0 +1,174 @@
+// src/tasks.rs
+// Répartition des collectes par enchères (contract-net): la station publie une tâche par gisement
+// connu, les collecteurs libres enchérissent (distance, énergie, chargement) et la meilleure offre
+// remporte le contrat. Une tâche est remise aux enchères si son titulaire échoue.
+use crate::types::{TileType, RobotMode, MAP_SIZE};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::TerrainData;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+
+// Marge de temps accordée au titulaire en plus du trajet estimé (en tours)
+pub const TASK_DEADLINE_MARGIN: u32 = 20;
+
+// Tâche de collecte d'un gisement
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollectionTask {
+    pub id: usize,
+    pub position: (usize, usize),
+    pub resource: TileType,
+    pub assignee: Option<usize>,   // Robot titulaire du contrat
+    pub deadline: u32,             // Tour au-delà duquel le contrat est retiré
+}
+
+// Offre d'un robot pour une tâche (coût: la plus basse l'emporte)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bid {
+    pub robot_id: usize,
+    pub task_id: usize,
+    pub cost: f32,
+    pub travel_tiles: usize,
+}
+
+// Statistiques du marché des tâches
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketStats {
+    pub published: usize,       // Tâches publiées
+    pub awarded: usize,         // Contrats attribués
+    pub reassigned: usize,      // Contrats retirés à un robot défaillant
+    pub completed: usize,       // Gisements épuisés alors qu'un robot en avait le contrat
+    pub withdrawn: usize,       // Gisements disparus sans titulaire
+}
+
+#[derive(Default)]
+pub struct TaskMarket {
+    tasks: Vec<CollectionTask>,
+    next_task_id: usize,
+    exhausted: HashMap<(usize, usize), u32>,   // Gisements vus épuisés et date de l'observation
+    pub stats: MarketStats,
+}
+
+impl TaskMarket {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tasks(&self) -> &[CollectionTask] {
+        &self.tasks
+    }
+
+    pub fn task_of(&self, robot_id: usize) -> Option<&CollectionTask> {
+        self.tasks.iter().find(|t| t.assignee == Some(robot_id))
+    }
+
+    // Un tour de marché: publier, retirer les contrats défaillants, enchérir et attribuer
+    pub fn run(&mut self, knowledge: &[Vec<TerrainData>], map: &Map, robots: &mut [Robot], now: u32) {
+        self.publish(knowledge, robots);
+        self.revoke_failed(map, robots, now);
+        self.auction(map, robots, now);
+
+        // Chaque robot connaît la position de son contrat
+        for robot in robots.iter_mut() {
+            robot.task = self.task_of(robot.id).map(|t| t.position);
+        }
+    }
+
+    // Publie les gisements connus de la station et clôt ceux qui ont disparu
+    fn publish(&mut self, knowledge: &[Vec<TerrainData>], robots: &[Robot]) {
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                let known = &knowledge[y][x];
+                let is_deposit = matches!(known.tile, TileType::Energy | TileType::Mineral | TileType::Scientific);
+                // Ne pas republier un gisement vu épuisé depuis la dernière observation de la station
+                let stale = self.exhausted.get(&(x, y)).is_some_and(|&seen| seen >= known.timestamp);
+                if known.explored && is_deposit && !stale && !self.tasks.iter().any(|t| t.position == (x, y)) {
+                    self.tasks.push(CollectionTask {
+                        id: self.next_task_id,
+                        position: (x, y),
+                        resource: known.tile.clone(),
+                        assignee: None,
+                        deadline: 0,
+                    });
+                    self.next_task_id += 1;
+                    self.stats.published += 1;
+                }
+            }
+        }
+
+        // Un gisement a disparu si la station ou le titulaire l'a vu épuisé
+        let stats = &mut self.stats;
+        let exhausted = &mut self.exhausted;
+        self.tasks.retain(|task| {
+            let (x, y) = task.position;
+            let seen_by_assignee = task.assignee
+                .and_then(|id| robots.iter().find(|r| r.id == id))
+                .map(|r| &r.memory[y][x])
+                .filter(|seen| seen.explored && seen.tile != task.resource);
+
+            let observed_at = match seen_by_assignee {
+                Some(seen) => seen.timestamp,
+                None if knowledge[y][x].tile != task.resource => knowledge[y][x].timestamp,
+                None => return true,
+            };
+
+            exhausted.insert(task.position, observed_at);
+            match task.assignee {
+                Some(_) => stats.completed += 1,
+                None => stats.withdrawn += 1,
+            }
+            false
+        });
+    }
+
+    // Retire les contrats des robots disparus, à court d'énergie, rappelés, partis en secours ou en retard
+    fn revoke_failed(&mut self, map: &Map, robots: &[Robot], now: u32) {
+        for task in self.tasks.iter_mut() {
+            let Some(id) = task.assignee else { continue };
+
+            let failed = match robots.iter().find(|r| r.id == id) {
+                None => true,
+                Some(robot) => robot.energy <= 0.0
+                    || robot.mode == RobotMode::ReturnToStation
+                    || robot.rescue.is_some()
+                    || robot.should_return_to_station(map)
+                    || now > task.deadline,
+            };
+
+            if failed {
+                task.assignee = None;
+                self.stats.reassigned += 1;
+            }
+        }
+    }
+
+    // Enchère: chaque robot libre fait une offre pour chaque tâche ouverte de sa ressource;
+    // les meilleures offres sont retenues tant que robot et tâche sont libres
+    fn auction(&mut self, map: &Map, robots: &[Robot], now: u32) {
+        let mut bids: Vec<Bid> = Vec::new();
+
+        for robot in robots {
+            if self.task_of(robot.id).is_some() {
+                continue;
+            }
+            for task in self.tasks.iter().filter(|t| t.assignee.is_none()) {
+                if let Some(bid) = robot.bid_for_task(map, task) {
+                    bids.push(bid);
+                }
+            }
+        }
+
+        bids.sort_by(|a, b| a.cost.total_cmp(&b.cost));
+
+        for bid in bids {
+            if self.task_of(bid.robot_id).is_some() {
+                continue;
+            }
+            if let Some(task) = self.tasks.iter_mut().find(|t| t.id == bid.task_id && t.assignee.is_none()) {
+                task.assignee = Some(bid.robot_id);
+                task.deadline = now + 2 * bid.travel_tiles as u32 + TASK_DEADLINE_MARGIN;
+                self.stats.awarded += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// src/traffic.rs
+// Circulation des robots: un seul robot par case (sauf sur une station), table de réservation
+// des prochains pas de chaque trajet et résolution des interblocages dans les couloirs étroits.
+use crate::map::Map;
+use crate::robot::Robot;
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// Nombre de pas réservés à l'avance sur le trajet d'un robot
+pub const RESERVATION_HORIZON: usize = 3;
+// Tours d'attente avant de chercher un détour
+pub const DETOUR_AFTER: u32 = 2;
+// Tours d'attente après lesquels un robot prioritaire cède lui aussi le passage
+pub const FORCED_YIELD_AFTER: u32 = 6;
+
+// Statistiques de circulation
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrafficStats {
+    pub blocked_moves: usize,       // Pas refusés car la case était prise
+    pub detours: usize,             // Trajets recalculés pour contourner un robot
+    pub deadlocks_resolved: usize,  // Interblocages résolus en cédant le passage
+}
+
+#[derive(Default)]
+pub struct TrafficControl {
+    exempt: HashSet<(usize, usize)>,                       // Stations: occupation multiple permise
+    occupied: HashMap<(usize, usize), usize>,              // Case -> robot qui l'occupe
+    reservations: HashMap<((usize, usize), u32), usize>,   // (case, tour) -> robot qui l'a réservée
+    waiting: HashMap<usize, ((usize, usize), u32)>,        // Robot -> case convoitée et tours d'attente
+    pub stats: TrafficStats,
+}
+
+impl TrafficControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Reconstruit l'occupation à partir des positions des robots (début de tour)
+    pub fn sync(&mut self, robots: &[Robot], now: u32) {
+        self.exempt = robots.iter().map(|r| (r.home_station_x, r.home_station_y)).collect();
+
+        self.occupied.clear();
+        for robot in robots {
+            let position = (robot.x, robot.y);
+            if !self.exempt.contains(&position) {
+                self.occupied.insert(position, robot.id);
+            }
+        }
+
+        self.reservations.retain(|&(_, time), _| time >= now);
+        self.waiting.retain(|id, _| robots.iter().any(|r| r.id == *id));
+    }
+
+    pub fn occupant(&self, pos: (usize, usize)) -> Option<usize> {
+        self.occupied.get(&pos).copied()
+    }
+
+    // Robot qui empêche `robot_id` d'entrer sur une case au tour `time`: son occupant,
+    // ou un robot prioritaire (id plus petit) qui l'a réservée pour ce tour
+    pub fn blocker(&self, robot_id: usize, pos: (usize, usize), time: u32) -> Option<usize> {
+        if self.exempt.contains(&pos) {
+            return None;
+        }
+
+        match self.occupied.get(&pos) {
+            Some(&id) if id != robot_id => return Some(id),
+            _ => {}
+        }
+
+        match self.reservations.get(&(pos, time)) {
+            Some(&id) if id < robot_id => Some(id),
+            _ => None,
+        }
+    }
+
+    // Réserve les prochains pas d'un trajet (remplace les réservations précédentes du robot)
+    pub fn reserve(&mut self, robot_id: usize, path: &VecDeque<(usize, usize)>, now: u32) {
+        self.reservations.retain(|_, id| *id != robot_id);
+
+        for (i, &pos) in path.iter().take(RESERVATION_HORIZON).enumerate() {
+            if !self.exempt.contains(&pos) {
+                self.reservations.entry((pos, now + 1 + i as u32)).or_insert(robot_id);
+            }
+        }
+    }
+
+    // Enregistre le déplacement d'un robot
+    pub fn moved(&mut self, robot_id: usize, from: (usize, usize), to: (usize, usize)) {
+        if self.occupied.get(&from) == Some(&robot_id) {
+            self.occupied.remove(&from);
+        }
+        if !self.exempt.contains(&to) {
+            self.occupied.insert(to, robot_id);
+        }
+        self.waiting.remove(&robot_id);
+    }
+
+    // Enregistre un pas refusé; retourne le nombre de tours d'attente consécutifs
+    pub fn wait(&mut self, robot_id: usize, wanted: (usize, usize)) -> u32 {
+        self.stats.blocked_moves += 1;
+
+        let entry = self.waiting.entry(robot_id).or_insert((wanted, 0));
+        entry.0 = wanted;
+        entry.1 += 1;
+        entry.1
+    }
+
+    // Interblocage: le robot qui nous bloque attend lui-même la case que nous occupons
+    pub fn is_deadlock(&self, position: (usize, usize), blocker: usize) -> bool {
+        matches!(self.waiting.get(&blocker), Some(&(wanted, _)) if wanted == position)
+    }
+
+    // Cases à éviter pour un détour: occupées ou réservées par d'autres robots
+    pub fn avoided_tiles(&self, robot_id: usize) -> HashSet<(usize, usize)> {
+        self.occupied.iter()
+            .filter(|(_, &id)| id != robot_id)
+            .map(|(&pos, _)| pos)
+            .chain(self.reservations.iter()
+                .filter(|(_, &id)| id != robot_id)
+                .map(|(&(pos, _), _)| pos))
+            .collect()
+    }
+
+    // Cases voisines où un robot peut se déplacer immédiatement
+    fn free_neighbors(&self, map: &Map, robot_id: usize, position: (usize, usize)) -> Vec<(usize, usize)> {
+        map.movement
+            .neighbors(position, |x, y| map.is_valid_position(x, y))
+            .into_iter()
+            .filter(|&pos| self.exempt.contains(&pos)
+                || self.occupied.get(&pos).is_none_or(|&id| id == robot_id))
+            .collect()
+    }
+
+    // Niche pour céder le passage à `other`: voisine libre, sur le côté (pas plus loin de lui
+    // qu'actuellement) et hors de son trajet réservé
+    pub fn side_refuge(&self, map: &Map, robot_id: usize, position: (usize, usize),
+                       other: usize, other_position: (usize, usize)) -> Option<(usize, usize)> {
+        let distance = |a: (usize, usize), b: (usize, usize)| a.0.abs_diff(b.0).max(a.1.abs_diff(b.1));
+        let current = distance(position, other_position);
+
+        self.free_neighbors(map, robot_id, position)
+            .into_iter()
+            .filter(|&pos| distance(pos, other_position) <= current)
+            .find(|&pos| !self.reservations.iter().any(|(&(p, _), &id)| p == pos && id == other))
+    }
+
+    // Recul faute de niche: n'importe quelle voisine libre
+    pub fn retreat(&self, map: &Map, robot_id: usize, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.free_neighbors(map, robot_id, position).first().copied()
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TileType {
     Empty,
     Obstacle,
@@ -9,12 +9,14 @@ pub enum TileType {
     Scientific,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RobotType {
     Explorer,          // Explore et cartographie le terrain
     EnergyCollector,   // Collecte de l'énergie
     MineralCollector,  // Collecte des minerais
     ScientificCollector, // Collecte des données scientifiques
+    Relay,             // Relais radio étendant la couverture de la station
+    Repair,            // Répare sur le terrain les robots usés
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -23,6 +25,7 @@ pub enum RobotMode {
     Collecting,       // Collecte des ressources
     ReturnToStation,  // Retour à la station
     Idle,             // En attente à la station
+    Stranded,         // En panne d'énergie, immobilisé en attendant les secours
 }
 
 pub const MAP_SIZE: usize = 20;
\ No newline at end of file
@@ -0,0 +1,279 @@
+// src/wear.rs
+// Usure du matériel: les moteurs s'usent à chaque pas, les capteurs à chaque tour de service et
+// la batterie perd de la capacité à chaque cycle de charge. Plus un composant est usé, plus il
+// risque une défaillance (tirée au sort avec le générateur aléatoire de la station) qui l'use
+// d'un coup. Sous un certain seuil, les capteurs usés réduisent la vision et les moteurs usés
+// ralentissent les déplacements. La station révise les robots à quai; les robots de réparation
+// remettent en état sur le terrain ceux qui en ont besoin.
+use crate::types::{RobotType, RobotMode};
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::events::SimulationEvent;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Graine par défaut du générateur aléatoire de la simulation
+pub const DEFAULT_SEED: u64 = 0xE2EEA;
+
+// Usure des moteurs par case parcourue et des capteurs par tour
+pub const MOTOR_WEAR_PER_STEP: f32 = 0.002;
+pub const SENSOR_WEAR_PER_TICK: f32 = 0.0005;
+// Capacité de batterie perdue par cycle de charge, et capacité minimale
+pub const BATTERY_FADE_PER_CYCLE: f32 = 0.01;
+pub const MIN_BATTERY_CAPACITY: f32 = 0.4;
+// Un amarrage sous cette fraction de la capacité compte comme un cycle de charge
+pub const CHARGE_CYCLE_DEPTH: f32 = 0.9;
+
+// Probabilité de défaillance par tour d'un composant hors d'usage (proportionnelle à l'usure)
+pub const FAILURE_RATE: f32 = 0.01;
+// État perdu par un composant défaillant
+pub const FAILURE_DAMAGE: f32 = 0.25;
+
+// État sous lequel un composant perd en performance
+pub const DEGRADED_BELOW: f32 = 0.7;
+// Vitesse minimale (fraction de case par tour) avec des moteurs hors d'usage
+pub const MIN_SPEED: f32 = 0.25;
+
+// État sous lequel un robot a besoin d'une réparation
+pub const REPAIR_THRESHOLD: f32 = 0.6;
+// État rendu par tour de réparation, sur le terrain et à quai
+pub const FIELD_REPAIR_RATE: f32 = 0.05;
+pub const STATION_REPAIR_RATE: f32 = 0.25;
+// Minerais consommés pour remplacer une batterie usée
+pub const BATTERY_REPLACEMENT_COST: u32 = 5;
+
+// Composant sujet à l'usure
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Component {
+    Motors,
+    Sensors,
+    Battery,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Component::Motors => write!(f, "moteurs"),
+            Component::Sensors => write!(f, "capteurs"),
+            Component::Battery => write!(f, "batterie"),
+        }
+    }
+}
+
+// État des composants d'un robot (1.0 = neuf)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Health {
+    pub motors: f32,
+    pub sensors: f32,
+    pub battery: f32,         // Capacité restante, en fraction de la capacité nominale
+    pub charge_cycles: u32,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Health {
+    pub fn new() -> Self {
+        Self { motors: 1.0, sensors: 1.0, battery: 1.0, charge_cycles: 0 }
+    }
+
+    pub fn get(&self, component: Component) -> f32 {
+        match component {
+            Component::Motors => self.motors,
+            Component::Sensors => self.sensors,
+            Component::Battery => self.battery,
+        }
+    }
+
+    // Composant le plus usé
+    pub fn worst(&self) -> f32 {
+        self.motors.min(self.sensors).min(self.battery)
+    }
+
+    // Moteurs ou capteurs à réparer (la batterie se remplace à la station)
+    pub fn needs_repair(&self) -> bool {
+        self.motors.min(self.sensors) < REPAIR_THRESHOLD
+    }
+
+    // Fraction de case parcourue par tour
+    pub fn speed(&self) -> f32 {
+        (self.motors / DEGRADED_BELOW).clamp(MIN_SPEED, 1.0)
+    }
+
+    // Portée de vision effective pour une portée nominale
+    pub fn vision(&self, range: usize) -> usize {
+        let factor = (self.sensors / DEGRADED_BELOW).min(1.0);
+        ((range as f32 * factor).round() as usize).max(1)
+    }
+
+    // Usure des moteurs après un pas
+    pub fn wear_step(&mut self) {
+        self.motors = (self.motors - MOTOR_WEAR_PER_STEP).max(0.0);
+    }
+
+    // Perte de capacité après un cycle de charge
+    pub fn charge_cycle(&mut self) {
+        self.charge_cycles += 1;
+        self.battery = (self.battery - BATTERY_FADE_PER_CYCLE).max(MIN_BATTERY_CAPACITY);
+    }
+
+    // Usure des capteurs sur un tour, puis tirage des défaillances: chaque composant défaille
+    // avec une probabilité proportionnelle à son usure. Retourne le composant défaillant.
+    pub fn wear_tick<R: Rng>(&mut self, rng: &mut R) -> Option<Component> {
+        self.sensors = (self.sensors - SENSOR_WEAR_PER_TICK).max(0.0);
+
+        for component in [Component::Motors, Component::Sensors, Component::Battery] {
+            if rng.gen::<f32>() < FAILURE_RATE * (1.0 - self.get(component)) {
+                match component {
+                    Component::Motors => self.motors = (self.motors - FAILURE_DAMAGE).max(0.0),
+                    Component::Sensors => self.sensors = (self.sensors - FAILURE_DAMAGE).max(0.0),
+                    Component::Battery => self.battery = (self.battery - FAILURE_DAMAGE).max(MIN_BATTERY_CAPACITY),
+                }
+                return Some(component);
+            }
+        }
+        None
+    }
+
+    // Remet en état moteurs et capteurs d'au plus `amount` chacun; retourne l'état rendu
+    pub fn repair(&mut self, amount: f32) -> f32 {
+        let motors = (self.motors + amount).min(1.0);
+        let sensors = (self.sensors + amount).min(1.0);
+        let restored = (motors - self.motors) + (sensors - self.sensors);
+        self.motors = motors;
+        self.sensors = sensors;
+        restored
+    }
+
+    // Moteurs et capteurs en parfait état
+    pub fn is_repaired(&self) -> bool {
+        self.motors >= 1.0 && self.sensors >= 1.0
+    }
+
+    // Batterie neuve
+    pub fn replace_battery(&mut self) {
+        self.battery = 1.0;
+        self.charge_cycles = 0;
+    }
+
+    // Résumé de l'état, ex. "M 92% C 88% B 97%"
+    pub fn summary(&self) -> String {
+        format!("M {:.0}% C {:.0}% B {:.0}%", self.motors * 100.0, self.sensors * 100.0, self.battery * 100.0)
+    }
+}
+
+// Statistiques d'usure et d'entretien
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WearStats {
+    pub failures: usize,            // Défaillances de composants
+    pub field_repairs: usize,       // Robots remis en état sur le terrain
+    pub station_repairs: usize,     // Robots remis en état à quai
+    pub batteries_replaced: usize,  // Batteries remplacées à la station
+}
+
+// Réparations sur le terrain: la station affecte à chaque robot usé le robot de réparation
+// disponible le plus proche, qui le remet en état une fois à son contact
+#[derive(Default)]
+pub struct MaintenanceService {
+    requests: BTreeMap<usize, (usize, usize)>,   // Robots à réparer et leur position
+    assignments: BTreeMap<usize, usize>,         // Robot à réparer -> robot de réparation
+    repairers: usize,
+    pub stats: WearStats,
+}
+
+impl MaintenanceService {
+    // Robots en attente de réparation sur le terrain
+    pub fn requests(&self) -> Vec<usize> {
+        self.requests.keys().copied().collect()
+    }
+
+    // Robot de réparation affecté à un robot usé
+    pub fn repairer_of(&self, robot_id: usize) -> Option<usize> {
+        self.assignments.get(&robot_id).copied()
+    }
+
+    // Position du robot qu'un robot de réparation doit rejoindre
+    pub fn assignment_of(&self, repairer_id: usize) -> Option<(usize, usize)> {
+        self.assignments.iter()
+            .find(|(_, &repairer)| repairer == repairer_id)
+            .and_then(|(id, _)| self.requests.get(id).copied())
+    }
+
+    // Des robots attendent une réparation et aucun robot de réparation n'est en service
+    pub fn needs_repairer(&self) -> bool {
+        !self.requests.is_empty() && self.repairers == 0
+    }
+
+    pub fn run(&mut self, map: &Map, robots: &mut [Robot]) -> Vec<SimulationEvent> {
+        let mut events = Vec::new();
+        self.repairers = robots.iter().filter(|r| r.robot_type == RobotType::Repair).count();
+
+        // Les robots usés sur le terrain demandent une réparation jusqu'à leur remise en état
+        self.requests.clear();
+        for robot in robots.iter_mut() {
+            let assigned = self.assignments.contains_key(&robot.id);
+            if !robot.is_at_station() && (robot.health.needs_repair() || (assigned && !robot.health.is_repaired())) {
+                self.requests.insert(robot.id, (robot.x, robot.y));
+            } else {
+                robot.under_repair = false;
+            }
+        }
+
+        // Abandonner les affectations devenues sans objet ou dont le réparateur n'est plus disponible
+        let available = |r: &Robot| r.robot_type == RobotType::Repair
+            && r.mode != RobotMode::Stranded
+            && r.rescue.is_none()
+            && !r.should_return_to_station(map);
+        self.assignments.retain(|id, repairer| {
+            self.requests.contains_key(id) && robots.iter().any(|r| r.id == *repairer && available(r))
+        });
+
+        // Affecter le réparateur libre le plus proche de chaque robot en attente
+        for (&id, &position) in self.requests.iter() {
+            if self.assignments.contains_key(&id) {
+                continue;
+            }
+            let repairer = robots.iter()
+                .filter(|r| r.id != id && available(r) && !self.assignments.values().any(|&a| a == r.id))
+                .filter_map(|r| map.travel_cost_to_goal((r.x, r.y), position).map(|cost| (cost, r.id)))
+                .min();
+            if let Some((_, repairer)) = repairer {
+                self.assignments.insert(id, repairer);
+            }
+        }
+
+        // Réparer au contact; le robot réparé reste sur place jusqu'à sa remise en état
+        let mut done = Vec::new();
+        for (&id, &repairer) in self.assignments.iter() {
+            let position = self.requests[&id];
+            let adjacent = robots.iter().any(|r| r.id == repairer && r.is_next_to(map, position));
+            let robot = match robots.iter_mut().find(|r| r.id == id) {
+                Some(robot) => robot,
+                None => continue,
+            };
+
+            robot.under_repair = adjacent;
+            if !adjacent {
+                continue;
+            }
+            robot.health.repair(FIELD_REPAIR_RATE);
+            if robot.health.is_repaired() {
+                robot.under_repair = false;
+                self.stats.field_repairs += 1;
+                events.push(SimulationEvent::RobotRepaired { robot_id: id, repairer_id: repairer });
+                done.push(id);
+            }
+        }
+        for id in done {
+            self.assignments.remove(&id);
+            self.requests.remove(&id);
+        }
+
+        events
+    }
+}
@@ -0,0 +1,96 @@
+// tests/audit.rs
+use ereea::audit::{merge_audited, ConflictQuery, ResolutionPolicy};
+use ereea::network::{apply_earth_command, create_station_data, EarthCommand};
+use ereea::robot::Robot;
+use ereea::scenario::Scenario;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType};
+
+fn observation(tile: TileType, robot_id: usize, robot_type: RobotType, timestamp: u32, confidence: f32) -> TerrainData {
+    TerrainData { explored: true, timestamp, robot_id, robot_type, tile, confidence }
+}
+
+// Robot à la station (2, 2) qui a observé `tile` en (x, y)
+fn robot_reporting(id: usize, robot_type: RobotType, station: &Station, x: usize, y: usize, data: TerrainData) -> Robot {
+    let mut robot = Robot::new_with_memory(2, 2, robot_type, id, 2, 2, station.global_memory.clone());
+    robot.learn((x, y), data);
+    robot
+}
+
+#[test]
+fn only_genuine_disagreements_are_recorded() {
+    let mut station = Station::new();
+    station.global_memory[5][4] = observation(TileType::Mineral, 1, RobotType::Explorer, 10, 1.0);
+    station.global_memory[6][4] = observation(TileType::Empty, 1, RobotType::Explorer, 10, 1.0);
+    station.current_time = 30;
+
+    // Même nature observée plus tard: simple rafraîchissement, pas de conflit
+    let mut robot = robot_reporting(2, RobotType::MineralCollector, &station, 4, 6,
+                                    observation(TileType::Empty, 2, RobotType::MineralCollector, 20, 1.0));
+    robot.learn((4, 5), observation(TileType::Empty, 2, RobotType::MineralCollector, 20, 1.0));
+    station.share_knowledge(&mut robot);
+
+    assert_eq!(station.conflict_count, 1);
+    assert_eq!(station.global_memory[6][4].timestamp, 20);
+    let conflicts = station.audit.query(&ConflictQuery::default());
+    assert_eq!(conflicts.len(), 1);
+    let conflict = &conflicts[0];
+    assert_eq!((conflict.time, conflict.position), (30, (4, 5)));
+    assert_eq!((conflict.known.tile.clone(), conflict.known.robot_id), (TileType::Mineral, 1));
+    assert_eq!((conflict.incoming.tile.clone(), conflict.incoming.robot_id), (TileType::Empty, 2));
+    assert_eq!(conflict.policy, ResolutionPolicy::Weighted);
+    assert!(conflict.incoming_won);
+
+    // Le robot repart avec la version retenue: le même désaccord n'est pas consigné deux fois
+    station.share_knowledge(&mut robot);
+    assert_eq!(station.audit.total(), 1);
+}
+
+#[test]
+fn policies_settle_disagreements_differently() {
+    let known = observation(TileType::Mineral, 1, RobotType::Explorer, 10, 0.9);
+    let newer_doubtful = observation(TileType::Empty, 2, RobotType::MineralCollector, 40, 0.3);
+
+    let settle = |policy| {
+        let mut tile = known.clone();
+        let (changed, conflict) = merge_audited(&mut tile, &newer_doubtful, policy, (0, 0), 50);
+        assert_eq!(conflict.unwrap().incoming_won, changed);
+        tile.tile
+    };
+
+    assert_eq!(settle(ResolutionPolicy::LatestWins), TileType::Empty);
+    assert_eq!(settle(ResolutionPolicy::MostConfident), TileType::Mineral);
+    assert_eq!(settle(ResolutionPolicy::TrustedType(RobotType::Explorer)), TileType::Mineral);
+    assert_eq!(settle(ResolutionPolicy::TrustedType(RobotType::MineralCollector)), TileType::Empty);
+}
+
+#[test]
+fn earth_selects_the_policy_and_queries_the_log() {
+    let mut station = Station::new();
+    apply_earth_command(&mut station, EarthCommand::MergePolicy(ResolutionPolicy::MostConfident));
+    assert_eq!(station.merge_policy, ResolutionPolicy::MostConfident);
+
+    // Trois désaccords sur deux cases, rapportés par deux robots
+    for (id, x, time) in [(2, 4, 10), (3, 4, 20), (3, 7, 30)] {
+        station.global_memory[3][x] = observation(TileType::Scientific, 1, RobotType::Explorer, time - 5, 0.5);
+        station.current_time = time;
+        let mut robot = robot_reporting(id, RobotType::Explorer, &station, x, 3,
+                                        observation(TileType::Obstacle, id, RobotType::Explorer, time, 0.8));
+        station.share_knowledge(&mut robot);
+    }
+
+    let query = ConflictQuery { position: Some((4, 3)), ..ConflictQuery::default() };
+    apply_earth_command(&mut station, EarthCommand::QueryConflicts(query));
+    let data = create_station_data(&station, 0);
+    assert_eq!(data.merge_policy, ResolutionPolicy::MostConfident);
+    assert_eq!(data.conflicts.iter().map(|c| c.time).collect::<Vec<_>>(), vec![10, 20]);
+
+    let by_robot = ConflictQuery { robot_id: Some(3), since: Some(25), ..ConflictQuery::default() };
+    assert_eq!(station.audit.query(&by_robot).iter().map(|c| c.position).collect::<Vec<_>>(), vec![(7, 3)]);
+    let latest = ConflictQuery { limit: 1, ..ConflictQuery::default() };
+    assert_eq!(station.audit.query(&latest)[0].time, 30);
+
+    // La règle peut aussi venir du scénario
+    let scenario = Scenario::parse(r#"{ "merge_policy": { "TrustedType": "Explorer" } }"#).unwrap();
+    assert_eq!(scenario.station().merge_policy, ResolutionPolicy::TrustedType(RobotType::Explorer));
+}
@@ -0,0 +1,78 @@
+// tests/behavior.rs
+// Un comportement externe peut remplacer celui d'un type de robot sans toucher à robot.rs
+use ereea::behavior::{Action, Behavior, BehaviorRegistry, Observation};
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+
+struct StayHome;
+
+impl Behavior for StayHome {
+    fn name(&self) -> &str {
+        "stay-home"
+    }
+
+    fn decide(&mut self, _observation: &Observation) -> Action {
+        Action::Idle
+    }
+}
+
+#[test]
+fn registered_behavior_drives_the_robot() {
+    let mut map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 10, 10, MovementRules::default());
+    let mut station = Station::new();
+
+    let mut registry = BehaviorRegistry::default();
+    registry.register(RobotType::Explorer, || Box::new(StayHome));
+    let mut robot = Robot::new(10, 10, RobotType::Explorer);
+    robot.set_behavior(registry.create(RobotType::Explorer));
+
+    for _ in 0..10 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+    }
+
+    assert_eq!(robot.behavior_name(), "stay-home");
+    assert_eq!((robot.x, robot.y), (10, 10));
+    assert_eq!(robot.mode, RobotMode::Idle);
+}
+
+#[test]
+fn default_explorer_leaves_the_station() {
+    let mut map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 10, 10, MovementRules::default());
+    let mut station = Station::new();
+    let mut robot = Robot::new(10, 10, RobotType::Explorer);
+
+    for _ in 0..5 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+    }
+
+    assert_eq!(robot.behavior_name(), "explorer");
+    assert_ne!((robot.x, robot.y), (10, 10));
+}
+
+#[test]
+fn return_depends_on_the_energy_needed_to_get_home() {
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default());
+
+    // Près de la station, 20% d'énergie suffit largement
+    let mut near = Robot::new(3, 2, RobotType::Explorer);
+    near.home_station_x = 2;
+    near.home_station_y = 2;
+    near.energy = near.max_energy * 0.2;
+    assert!(!Observation::gather(&near, &map).needs_return);
+
+    // Loin de la station, rentrer dès que l'énergie couvre juste le retour et la marge
+    let mut far = Robot::new(17, 17, RobotType::Explorer);
+    far.home_station_x = 2;
+    far.home_station_y = 2;
+    let needed = far.energy_to_return(&map).unwrap();
+    far.energy = needed + far.safety_margin(needed);
+    assert!(Observation::gather(&far, &map).needs_return);
+
+    far.energy = far.max_energy * 0.5;
+    assert!(!Observation::gather(&far, &map).needs_return);
+}
@@ -0,0 +1,90 @@
+// tests/comms.rs
+use ereea::comms::CommNetwork;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+fn open_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default())
+}
+
+fn robot_at(id: usize, x: usize, y: usize, robot_type: RobotType) -> Robot {
+    let station = Station::new();
+    Robot::new_with_memory(x, y, robot_type, id, 2, 2, station.global_memory)
+}
+
+#[test]
+fn relays_extend_coverage_in_a_chain() {
+    let mut comms = CommNetwork::new(5, 4);
+    let robots = vec![
+        robot_at(1, 16, 2, RobotType::Explorer),
+        robot_at(2, 7, 2, RobotType::Relay),
+        robot_at(3, 11, 2, RobotType::Relay),
+    ];
+
+    comms.update((2, 2), &robots[..1]);
+    assert!(!comms.is_connected(1));
+    assert_eq!(comms.out_of_contact().len(), 1);
+
+    // Station (portée 5) -> relais en x=7 -> relais en x=11 (portée 4) -> explorateur en x=15
+    comms.update((2, 2), &robots);
+    assert!(comms.is_connected(2) && comms.is_connected(3));
+    assert!(comms.covers((15, 2)));
+    assert!(!comms.is_connected(1));
+
+    let mut robots = robots;
+    robots[0].x = 15;
+    comms.update((2, 2), &robots);
+    assert!(comms.is_connected(1));
+    assert!(comms.out_of_contact().is_empty());
+}
+
+#[test]
+fn relay_post_stays_within_the_rest_of_the_network() {
+    let map = open_map();
+    let mut comms = CommNetwork::new(5, 4);
+    let robots = vec![robot_at(1, 17, 17, RobotType::Explorer), robot_at(2, 2, 2, RobotType::Relay)];
+    comms.update((2, 2), &robots);
+
+    // Le poste est à la limite de la portée de la station, en direction de l'explorateur
+    assert_eq!(comms.relay_post(2, (2, 2), &map), Some((7, 7)));
+}
+
+#[test]
+fn robots_in_contact_stream_observations_to_the_station() {
+    let map = open_map();
+    let mut station = Station::new();
+    let mut robots = vec![robot_at(1, 5, 5, RobotType::Explorer), robot_at(2, 15, 15, RobotType::Explorer)];
+    robots[0].update_memory(&map, &mut station);
+    robots[1].update_memory(&map, &mut station);
+
+    station.update_communications(&map, &mut robots);
+
+    assert!(robots[0].in_contact);
+    assert!(!robots[1].in_contact);
+    assert!(station.global_memory[5][5].explored);
+    assert!(!station.global_memory[15][15].explored);
+    // Cercle de vision de rayon 3 de l'explorateur
+    assert_eq!(station.knowledge_stats.tiles_via_radio, 37);
+}
+
+#[test]
+fn streamed_observations_are_not_sent_back_at_the_next_sync() {
+    let map = open_map();
+    let mut station = Station::new();
+    let mut robots = vec![robot_at(1, 5, 5, RobotType::Explorer), robot_at(2, 7, 5, RobotType::Explorer)];
+    robots[0].update_memory(&map, &mut station);
+    robots[1].update_memory(&map, &mut station);
+
+    station.update_communications(&map, &mut robots);
+
+    // Chaque robot ne recevra que les cases retenues qu'il ne détient pas, même si ses envois
+    // s'intercalent avec ceux de l'autre dans la réplique de la station
+    for robot in robots.iter() {
+        let delta = station.replica.delta(&station.global_memory, &robot.clock);
+        assert!(!delta.is_empty());
+        assert!(delta.ops.iter().all(|op| robot.memory[op.position.1][op.position.0] != op.data));
+    }
+}
@@ -0,0 +1,80 @@
+// tests/construction.rs
+use ereea::construction::BuildOrigin;
+use ereea::economy::{BuildCost, Economy};
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::network::{apply_earth_command, create_station_data, EarthCommand};
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+// Carte sans ressources: le planificateur de la station commande des explorateurs
+fn barren_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default())
+}
+
+// Constructions rapides et bon marché
+fn quick_station(energy: u32) -> Station {
+    let economy = Economy {
+        starting_energy: energy,
+        default_build_cost: BuildCost { energy: 10, minerals: 0, build_time: 2 },
+        ..Economy::default()
+    };
+    Station::with_economy(1, economy)
+}
+
+#[test]
+fn orders_are_built_one_at_a_time_in_order() {
+    let map = barren_map();
+    let mut station = quick_station(100);
+    station.enqueue_build(RobotType::Relay, BuildOrigin::Earth);
+    station.enqueue_build(RobotType::Repair, BuildOrigin::Earth);
+
+    let mut built = Vec::new();
+    for _ in 0..6 {
+        if let Some(robot) = station.advance_construction(&map, &[]) {
+            built.push(robot.robot_type);
+        }
+    }
+
+    // Le planificateur ne commande qu'une fois la file vidée
+    assert_eq!(built, vec![RobotType::Relay, RobotType::Repair, RobotType::Explorer]);
+    assert_eq!(station.energy_reserves, 70);
+    assert!(station.construction.is_empty());
+}
+
+#[test]
+fn cancelling_an_order_refunds_its_reservation() {
+    let map = barren_map();
+    let mut station = quick_station(15);
+    let first = station.enqueue_build(RobotType::Relay, BuildOrigin::Earth);
+    let second = station.enqueue_build(RobotType::Explorer, BuildOrigin::Earth);
+
+    assert!(station.advance_construction(&map, &[]).is_none());
+    assert_eq!(station.energy_reserves, 5);
+
+    // Commande en attente: rien à restituer; commande en cours: coût restitué
+    assert!(station.cancel_build(second));
+    assert_eq!(station.energy_reserves, 5);
+    assert!(station.cancel_build(first));
+    assert_eq!(station.energy_reserves, 15);
+    assert!(!station.cancel_build(first));
+    assert!(station.construction.is_empty());
+}
+
+#[test]
+fn earth_commands_reach_the_queue_and_are_streamed_back() {
+    let mut station = quick_station(100);
+
+    let line = serde_json::to_string(&EarthCommand::Build(RobotType::Relay)).unwrap();
+    apply_earth_command(&mut station, serde_json::from_str(&line).unwrap());
+    apply_earth_command(&mut station, EarthCommand::Build(RobotType::Explorer));
+
+    let data = create_station_data(&station, 0);
+    assert_eq!(data.build_queue.len(), 2);
+    assert_eq!(data.build_queue[0].robot_type, RobotType::Relay);
+    assert_eq!(data.build_queue[0].origin, BuildOrigin::Earth);
+
+    let id = data.build_queue[1].id;
+    apply_earth_command(&mut station, EarthCommand::CancelBuild(id));
+    assert_eq!(station.construction.orders().len(), 1);
+}
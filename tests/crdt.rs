@@ -0,0 +1,221 @@
+// tests/crdt.rs
+use ereea::audit::ResolutionPolicy;
+use ereea::crdt::{join, resync, sync, Dot, Replica, VectorClock};
+use ereea::robot::Robot;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType};
+use proptest::prelude::*;
+
+// Stations répliquées: principale et deux avant-postes
+const REPLICAS: usize = 3;
+// Les observations tombent dans un coin de la carte pour multiplier les désaccords
+const AREA: usize = 4;
+
+#[derive(Clone, Debug)]
+enum Event {
+    // Rapport d'un robot à une station: (station, x, y, robot, nature, confiance en dixièmes)
+    Report(usize, usize, usize, usize, u8, u8),
+    // Delta d'une station vers une autre
+    Push(usize, usize),
+}
+
+fn event() -> impl Strategy<Value = Event> {
+    prop_oneof![
+        (0..REPLICAS, 0..AREA, 0..AREA, 1..5usize, 0..3u8, 1..=10u8)
+            .prop_map(|(replica, x, y, robot, tile, confidence)| Event::Report(replica, x, y, robot, tile, confidence)),
+        (0..REPLICAS, 0..REPLICAS).prop_map(|(from, to)| Event::Push(from, to)),
+    ]
+}
+
+fn observation(robot_id: usize, tile: u8, confidence: u8, timestamp: u32) -> TerrainData {
+    TerrainData {
+        explored: true,
+        timestamp,
+        robot_id,
+        robot_type: if robot_id.is_multiple_of(2) { RobotType::Explorer } else { RobotType::MineralCollector },
+        tile: [TileType::Empty, TileType::Mineral, TileType::Obstacle][tile as usize].clone(),
+        confidence: confidence as f32 / 10.0,
+    }
+}
+
+struct Stations {
+    memories: Vec<Vec<Vec<TerrainData>>>,
+    replicas: Vec<Replica>,
+}
+
+impl Stations {
+    fn new() -> Self {
+        Self {
+            memories: (0..REPLICAS).map(|_| Station::new().global_memory).collect(),
+            replicas: (0..REPLICAS).map(Replica::new).collect(),
+        }
+    }
+
+    fn push(&mut self, from: usize, to: usize, policy: ResolutionPolicy) {
+        let delta = self.replicas[from].delta(&self.memories[from], &self.replicas[to].clock);
+        self.replicas[to].apply(&mut self.memories[to], &delta, policy);
+    }
+
+    // Changement de règle: chaque avant-poste réévalue tout avec la station principale, deux fois
+    fn resync(&mut self, policy: ResolutionPolicy) {
+        let (main_memory, outpost_memories) = self.memories.split_at_mut(1);
+        let (main, outposts) = self.replicas.split_at_mut(1);
+        for _ in 0..2 {
+            for (memory, outpost) in outpost_memories.iter_mut().zip(outposts.iter_mut()) {
+                resync(&mut main_memory[0], &mut main[0], memory, outpost, policy);
+            }
+        }
+    }
+
+    // Chaque station envoie son delta à chacune des autres, deux fois de suite
+    fn gossip(&mut self, policy: ResolutionPolicy) {
+        for _ in 0..2 {
+            for from in 0..REPLICAS {
+                for to in 0..REPLICAS {
+                    if from != to {
+                        self.push(from, to, policy);
+                    }
+                }
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn replicas_converge_whatever_the_sync_order(events in prop::collection::vec(event(), 0..80),
+                                                 policy in prop::sample::select(ResolutionPolicy::ALL.to_vec())) {
+        let mut stations = Stations::new();
+        let mut expected = Station::new().global_memory;
+
+        for (time, event) in events.iter().enumerate() {
+            match *event {
+                Event::Report(replica, x, y, robot, tile, confidence) => {
+                    let data = observation(robot, tile, confidence, time as u32 + 1);
+                    join(&mut expected[y][x], &data, policy);
+                    stations.replicas[replica].ingest(&mut stations.memories[replica], (x, y), &data, policy);
+                },
+                Event::Push(from, to) => stations.push(from, to, policy),
+            }
+        }
+        stations.gossip(policy);
+
+        // Toutes les stations retiennent, pour chaque case, la plus forte des observations rapportées
+        for replica in 0..REPLICAS {
+            prop_assert_eq!(&stations.memories[replica], &expected);
+            prop_assert_eq!(&stations.replicas[replica].clock, &stations.replicas[0].clock);
+        }
+    }
+
+    #[test]
+    fn replicas_converge_when_the_policy_changes_during_the_run(
+        events in prop::collection::vec(event(), 0..80),
+        switch in 0..80usize,
+        policies in prop::collection::vec(prop::sample::select(ResolutionPolicy::ALL.to_vec()), 2)) {
+        let mut stations = Stations::new();
+        let mut policy = policies[0];
+
+        for (time, event) in events.iter().enumerate() {
+            if time == switch {
+                policy = policies[1];
+                stations.resync(policy);
+            }
+            match *event {
+                Event::Report(replica, x, y, robot, tile, confidence) => {
+                    let data = observation(robot, tile, confidence, time as u32 + 1);
+                    stations.replicas[replica].ingest(&mut stations.memories[replica], (x, y), &data, policy);
+                },
+                Event::Push(from, to) => stations.push(from, to, policy),
+            }
+        }
+        stations.gossip(policy);
+
+        for replica in 1..REPLICAS {
+            prop_assert_eq!(&stations.memories[replica], &stations.memories[0]);
+        }
+    }
+
+    #[test]
+    fn tile_merge_is_commutative_associative_and_idempotent(
+        tiles in prop::collection::vec((1..5usize, 0..3u8, 1..=10u8, 1..20u32), 3),
+        policy in prop::sample::select(ResolutionPolicy::ALL.to_vec())) {
+        // Deux observations distinctes n'ont jamais la même date et le même robot
+        let [a, b, c] = [0, 1, 2].map(|i| {
+            let (robot, tile, confidence, timestamp) = tiles[i];
+            observation(robot, tile, confidence, timestamp * 3 + i as u32)
+        });
+        let merged = |first: &TerrainData, others: &[&TerrainData]| {
+            let mut tile = first.clone();
+            for other in others {
+                join(&mut tile, other, policy);
+            }
+            tile
+        };
+
+        prop_assert_eq!(merged(&a, &[&b]), merged(&b, &[&a]));
+        prop_assert_eq!(merged(&merged(&a, &[&b]), &[&c]), merged(&a, &[&merged(&b, &[&c])]));
+        prop_assert_eq!(merged(&a, &[&a]), a.clone());
+    }
+}
+
+#[test]
+fn replicas_only_exchange_unseen_tiles() {
+    let policy = ResolutionPolicy::default();
+    let mut stations = Stations::new();
+    for x in 0..AREA {
+        let data = observation(1, 1, 10, x as u32 + 1);
+        stations.replicas[0].ingest(&mut stations.memories[0], (x, 0), &data, policy);
+    }
+
+    let [main, outpost, ..] = &mut stations.replicas[..] else { unreachable!() };
+    let (main_memory, outpost_memory) = stations.memories.split_at_mut(1);
+    assert_eq!(sync(&mut main_memory[0], main, &mut outpost_memory[0], outpost, policy), AREA);
+    assert!(outpost.clock.dominates(&main.clock));
+
+    // Une fois à jour, l'avant-poste ne reçoit plus que les nouvelles observations
+    assert!(main.delta(&main_memory[0], &outpost.clock).is_empty());
+    main.ingest(&mut main_memory[0], (0, 1), &observation(2, 2, 10, 10), policy);
+    let delta = main.delta(&main_memory[0], &outpost.clock);
+    assert_eq!(delta.ops.iter().map(|op| op.position).collect::<Vec<_>>(), vec![(0, 1)]);
+    assert_eq!(outpost.apply(&mut outpost_memory[0], &delta, policy), 1);
+    assert_eq!(outpost_memory[0][1][0].robot_id, 2);
+}
+
+#[test]
+fn clocks_keep_isolated_dots_until_the_gap_is_filled() {
+    let mut clock = VectorClock::default();
+    clock.add(Dot { replica: 0, counter: 2 });
+    assert!(clock.covers(Dot { replica: 0, counter: 2 }));
+    assert!(!clock.covers(Dot { replica: 0, counter: 1 }));
+    assert_eq!(clock.get(0), 0);
+
+    clock.add(Dot { replica: 0, counter: 1 });
+    assert_eq!(clock.get(0), 2);
+
+    // Une horloge qui n'a vu que le point isolé ne couvre pas la suite continue
+    let mut isolated = VectorClock::default();
+    isolated.add(Dot { replica: 0, counter: 2 });
+    assert!(clock.dominates(&isolated) && !isolated.dominates(&clock));
+}
+
+#[test]
+fn docked_robots_receive_news_and_the_retained_version_of_their_rejected_tiles() {
+    let mut station = Station::new();
+    station.merge_policy = ResolutionPolicy::MostConfident;
+    let mut sure = Robot::new_with_memory(2, 2, RobotType::MineralCollector, 1, 2, 2, station.global_memory.clone());
+    sure.learn((4, 5), observation(1, 1, 9, 5));
+    station.share_knowledge(&mut sure);
+
+    // Observation plus récente mais moins sûre: écartée, le robot repart avec la version retenue
+    let mut doubtful = Robot::new_with_memory(2, 2, RobotType::Explorer, 2, 2, 2, station.global_memory.clone());
+    doubtful.learn((4, 5), observation(2, 0, 3, 10));
+    doubtful.learn((4, 6), observation(2, 0, 3, 10));
+    station.share_knowledge(&mut doubtful);
+    assert_eq!(doubtful.memory[5][4], sure.memory[5][4]);
+    assert_eq!(station.global_memory[6][4].robot_id, 2);
+
+    // Le robot à jour n'a plus rien à recevoir; l'autre ne recevra que la case apprise depuis
+    assert!(station.replica.delta(&station.global_memory, &doubtful.clock).is_empty());
+    let news = station.replica.delta(&station.global_memory, &sure.clock);
+    assert_eq!(news.ops.iter().map(|op| op.position).collect::<Vec<_>>(), vec![(4, 6)]);
+}
@@ -0,0 +1,116 @@
+// tests/docking.rs
+use ereea::behavior::Action;
+use ereea::docking::Docking;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+const STATION: (usize, usize) = (2, 2);
+
+fn open_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], STATION.0, STATION.1, MovementRules::default())
+}
+
+fn robot(id: usize, x: usize, y: usize, station: &Station) -> Robot {
+    Robot::new_with_memory(x, y, RobotType::Explorer, id, STATION.0, STATION.1, station.global_memory.clone())
+}
+
+#[test]
+fn queue_serves_arrival_order_after_robots_bringing_energy() {
+    let station = Station::new();
+    let mut docking = Docking::default();
+    let mut charging = robot(1, STATION.0, STATION.1, &station);
+    charging.charging = true;
+    docking.sync(&[charging], 1);
+
+    // Baie unique occupée: les demandes s'accumulent dans la file
+    assert!(!docking.request(2, STATION, 1, false, 1));
+    assert!(!docking.request(3, STATION, 1, false, 1));
+    assert!(!docking.request(4, STATION, 1, true, 1));
+    assert_eq!(docking.queue_at(STATION), vec![4, 2, 3]);
+    assert_eq!(docking.stats.queue_waits, 3);
+    assert_eq!(docking.stats.longest_queue, 3);
+
+    // La baie se libère: seul le premier de la file y entre
+    docking.sync(&[], 2);
+    assert!(!docking.request(2, STATION, 1, false, 2));
+    assert!(docking.request(4, STATION, 1, true, 2));
+    assert_eq!(docking.docked_at(STATION), 1);
+    assert_eq!(docking.queue_at(STATION), vec![2, 3]);
+
+    // La file oublie les robots qui ne demandent plus de baie
+    docking.sync(&[], 5);
+    assert!(docking.queue_at(STATION).is_empty());
+}
+
+#[test]
+fn robots_wait_next_to_a_full_station() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.economy.docking_bays = 1;
+    let mut robots = vec![robot(1, STATION.0, STATION.1, &station), robot(2, 3, 2, &station)];
+    robots[0].charging = true;
+
+    station.tick();
+    station.update_traffic(&robots);
+    robots[1].perform(Action::ReturnToStation, &mut map, &mut station);
+    assert_eq!((robots[1].x, robots[1].y), (3, 2));
+    assert_eq!(station.docking.queue_at(STATION), vec![2]);
+
+    // Le robot amarré a fini sa recharge: la baie est accordée au robot en attente, même s'il
+    // reste sur le quai
+    robots[0].charging = false;
+    station.tick();
+    station.update_traffic(&robots);
+    robots[1].perform(Action::ReturnToStation, &mut map, &mut station);
+    assert!(robots[1].is_at_station() && robots[1].charging);
+    assert!(station.docking.queue_at(STATION).is_empty());
+}
+
+#[test]
+fn robots_crossing_a_full_station_do_not_queue() {
+    // Murs de part et d'autre de la station: le passage de (3, 2) à (1, 2) la traverse
+    let mut map = open_map();
+    map.set_tile(2, 1, TileType::Obstacle);
+    map.set_tile(2, 3, TileType::Obstacle);
+    let mut station = Station::new();
+    station.economy.docking_bays = 1;
+    let mut robots = vec![robot(1, STATION.0, STATION.1, &station), robot(2, 3, 2, &station)];
+    robots[0].charging = true;
+
+    for _ in 0..2 {
+        station.tick();
+        station.update_traffic(&robots);
+        robots[1].perform(Action::MoveTo((1, 2)), &mut map, &mut station);
+        assert!(!robots[1].charging);
+        assert!(station.docking.queue_at(STATION).is_empty());
+    }
+    assert_eq!((robots[1].x, robots[1].y), (1, 2));
+}
+
+#[test]
+fn charging_is_rate_limited_and_drains_the_reserves() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.economy.charge_rate = 10;
+    station.energy_reserves = 25;
+    let mut docked = robot(1, STATION.0, STATION.1, &station);
+    docked.energy = 1.0;
+
+    // Le robot reste dans sa baie tant que la recharge se poursuit
+    docked.update(&mut map, &mut station);
+    assert!(docked.charging && docked.is_at_station());
+    assert!(docked.energy < 11.0);
+    assert_eq!(station.energy_reserves, 15);
+
+    for _ in 0..3 {
+        station.tick();
+        docked.update(&mut map, &mut station);
+    }
+    assert_eq!(station.energy_reserves, 0);
+    assert_eq!(station.docking.stats.energy_delivered, 25);
+    assert!(docked.energy < 27.0);
+    assert!(!docked.charging);
+}
@@ -0,0 +1,114 @@
+// tests/economy.rs
+use ereea::economy::{BuildCost, Economy};
+use ereea::inventory::CargoKind;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::scenario::Scenario;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+use std::path::Path;
+
+// Carte sans ressources: la station construit des explorateurs
+fn barren_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default())
+}
+
+#[test]
+fn scenario_overrides_only_the_fields_it_sets() {
+    let scenario = Scenario::parse(r#"{
+        "economy": {
+            "starting_energy": 300,
+            "build_costs": { "Explorer": { "energy": 20, "minerals": 5, "build_time": 10 } }
+        }
+    }"#).unwrap();
+
+    assert_eq!(scenario.seed, None);
+    assert_eq!(scenario.economy.starting_energy, 300);
+    assert_eq!(scenario.economy.mineral_energy, Economy::default().mineral_energy);
+    assert_eq!(scenario.economy.build_cost(RobotType::Explorer).energy, 20);
+    assert_eq!(scenario.economy.build_cost(RobotType::Relay), &BuildCost::default());
+
+    let station = scenario.station();
+    assert_eq!(station.energy_reserves, 300);
+    assert!(Scenario::parse("{ \"economy\": 3 }").is_err());
+
+    // Le scénario d'exemple fourni avec le projet se charge
+    let example = Scenario::load(Path::new("scenarios/economie_tendue.json")).unwrap();
+    assert_eq!(example.seed, Some(42));
+    assert!(example.economy.upkeep_per_robot > 0.0);
+}
+
+#[test]
+fn builds_follow_the_cost_and_duration_of_each_type() {
+    let map = barren_map();
+    let mut economy = Economy { starting_energy: 100, starting_minerals: 20, ..Economy::default() };
+    economy.build_costs.insert(RobotType::Explorer, BuildCost { energy: 40, minerals: 10, build_time: 5 });
+    let mut station = Station::with_economy(1, economy);
+
+    // Coût réservé dès le premier tour, robot livré après cinq tours de construction
+    for _ in 0..4 {
+        assert!(station.advance_construction(&map, &[]).is_none());
+    }
+    assert_eq!((station.energy_reserves, station.collected_minerals), (60, 10));
+    assert!(station.get_status().contains("Construction Explorer: 4/5 tours"));
+    assert!(station.advance_construction(&map, &[]).is_some());
+
+    // La commande suivante réserve à son tour; la troisième attend des ressources
+    for _ in 0..5 {
+        station.advance_construction(&map, &[]);
+    }
+    assert_eq!((station.energy_reserves, station.collected_minerals), (20, 0));
+    assert!(station.advance_construction(&map, &[]).is_none());
+    assert!(station.get_status().contains("Création Explorer: 20/40 énergie, 0/10 minerai"));
+}
+
+#[test]
+fn deposits_and_upkeep_use_the_configured_rates() {
+    let economy = Economy { mineral_energy: 3, cell_energy: 7, upkeep_per_robot: 0.25, ..Economy::default() };
+    let mut station = Station::with_economy(1, economy);
+    let start = station.energy_reserves;
+
+    station.deposit(CargoKind::Mineral, 2);
+    station.deposit(CargoKind::EnergyCell, 1);
+    assert_eq!(station.energy_reserves, start + 6 + 7);
+
+    // 0,25 par robot et par tour: 3 robots pendant 4 tours = 3 unités d'énergie
+    for _ in 0..4 {
+        station.pay_upkeep(3);
+    }
+    assert_eq!(station.energy_reserves, start + 13 - 3);
+}
+
+#[test]
+fn field_charging_uses_the_configured_cell_energy() {
+    let mut map = barren_map();
+    let economy = Economy { cell_energy: 4, ..Economy::default() };
+    let mut station = Station::with_economy(1, economy);
+    let mut collector = Robot::new_with_memory(15, 15, RobotType::EnergyCollector, 1, 2, 2, station.global_memory.clone());
+    collector.inventory.add(CargoKind::EnergyCell, 2);
+    collector.energy = 1.0;
+
+    // À court d'énergie, le collecteur ouvre ses deux cellules de 4 unités chacune
+    station.tick();
+    collector.update(&mut map, &mut station);
+    assert_eq!(collector.inventory.amount(CargoKind::EnergyCell), 0);
+    assert!(collector.energy > 1.0 + 8.0 - 1.0 && collector.energy <= 9.0);
+
+    // Dépôt, recharge sur le terrain et transfert comptent la même énergie par cellule
+    collector.inventory.add(CargoKind::EnergyCell, 3);
+    assert_eq!(collector.spare_energy(&map, (15, 15), station.economy.cell_energy), 12.0);
+    let mut explorer = Robot::new_with_memory(15, 16, RobotType::Explorer, 2, 2, 2, station.global_memory.clone());
+    explorer.energy = 0.0;
+    assert_eq!(collector.transfer_energy(&mut explorer, 8.0, station.economy.cell_energy), 8.0);
+    assert_eq!(collector.inventory.amount(CargoKind::EnergyCell), 1);
+}
+
+#[test]
+fn scenario_seed_fixes_the_map() {
+    let scenario = Scenario::parse(r#"{ "seed": 7 }"#).unwrap();
+    assert_eq!(scenario.map().tiles(), scenario.map().tiles());
+
+    let other = Scenario::parse(r#"{ "seed": 8 }"#).unwrap();
+    assert_ne!(scenario.map().tiles(), other.map().tiles());
+}
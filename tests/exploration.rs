@@ -0,0 +1,110 @@
+// tests/exploration.rs
+use ereea::behavior::Action;
+use ereea::exploration::{find_frontiers, rank_frontiers};
+use ereea::map::Map;
+use ereea::movement::{MovementRules, DIAGONAL_STEP_COST, STEP_COST};
+use ereea::robot::{Robot, RETURN_SAFETY_MARGIN};
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+
+fn memory_with_known_box(min: usize, max: usize) -> Vec<Vec<TerrainData>> {
+    let unknown = TerrainData { explored: false, timestamp: 0, robot_id: 0, robot_type: RobotType::Explorer, tile: TileType::Empty, confidence: 0.0 };
+    let mut memory = vec![vec![unknown; MAP_SIZE]; MAP_SIZE];
+    for row in memory.iter_mut().take(max + 1).skip(min) {
+        for tile in row.iter_mut().take(max + 1).skip(min) {
+            tile.explored = true;
+        }
+    }
+    memory
+}
+
+fn open_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 10, 10, MovementRules::default())
+}
+
+#[test]
+fn boundary_of_known_area_forms_one_frontier() {
+    let map = open_map();
+    let memory = memory_with_known_box(7, 13);
+    let frontiers = find_frontiers(&memory, &map, 2);
+
+    // Le contour du carré connu est un seul groupe de 24 cases
+    assert_eq!(frontiers.len(), 1);
+    assert_eq!(frontiers[0].cells.len(), 24);
+    assert!(frontiers[0].information_gain > 0);
+}
+
+#[test]
+fn frontiers_beyond_the_return_budget_are_discarded() {
+    let map = open_map();
+    let memory = memory_with_known_box(7, 13);
+    let frontiers = find_frontiers(&memory, &map, 2);
+
+    let reachable = rank_frontiers(frontiers.clone(), &map, (10, 10), (10, 10), 100.0, |to, back| (to + back) as f32 / 10.0);
+    assert_eq!(reachable.len(), 1);
+
+    // Aller-retour de 6 cases au minimum: impossible avec 5 unités d'énergie
+    let too_far = rank_frontiers(frontiers, &map, (10, 10), (10, 10), 5.0, |to, back| (to + back) as f32 / 10.0);
+    assert!(too_far.is_empty());
+}
+
+#[test]
+fn explorers_only_commit_to_frontiers_they_can_leave_without_running_low() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let memory = memory_with_known_box(7, 13);
+    let mut robot = Robot::new_with_memory(10, 10, RobotType::Explorer, 1, 10, 10, memory);
+
+    // Frontière la plus proche à 3 cases: l'aller-retour plus la seule marge fixe ne suffit plus
+    // une fois la marge proportionnelle et le pas suivant comptés
+    let round_trip = robot.energy_for_travel(6 * STEP_COST);
+    robot.energy = round_trip + RETURN_SAFETY_MARGIN + 0.1;
+    robot.perform(Action::Explore, &mut map, &mut station);
+    assert_eq!(robot.exploration_target, None);
+
+    // Avec de quoi rentrer de la frontière au-dessus du seuil, il s'y engage sans être à court
+    let back = robot.energy_for_travel(3 * STEP_COST);
+    robot.energy = round_trip + robot.energy_for_travel(DIAGONAL_STEP_COST) + robot.safety_margin(back) + 0.1;
+    robot.perform(Action::Explore, &mut map, &mut station);
+    let target = robot.exploration_target.unwrap();
+    while (robot.x, robot.y) != target {
+        robot.perform(Action::Explore, &mut map, &mut station);
+    }
+    assert!(!robot.is_low_on_energy(&map));
+}
+
+#[test]
+fn explorers_claim_different_frontiers_and_release_them_when_stranded() {
+    // Couloir connu entre deux murs: une frontière à chaque bout, à égale distance de la station
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    let mut memory = memory_with_known_box(8, 12);
+    for (y, row) in memory.iter_mut().enumerate().take(13).skip(8) {
+        for (x, tile) in row.iter_mut().enumerate().take(19).skip(2) {
+            tile.explored = (3..=17).contains(&x) || y == 8 || y == 12;
+        }
+    }
+    for row in [8, 12] {
+        tiles[row][2..=18].fill(TileType::Obstacle);
+    }
+    let mut map = Map::from_tiles(tiles, 10, 10, MovementRules::default());
+    let mut station = Station::new();
+    let mut robots: Vec<Robot> = (1..=2)
+        .map(|id| Robot::new_with_memory(10, 10, RobotType::Explorer, id, 10, 10, memory.clone()))
+        .collect();
+    assert_eq!(find_frontiers(&memory, &map, robots[0].vision_range()).len(), 2);
+
+    for robot in robots.iter_mut() {
+        robot.perform(Action::Explore, &mut map, &mut station);
+    }
+    let first = station.frontier_claims[&1];
+    let second = station.frontier_claims[&2];
+    assert_eq!([first.0, second.0].iter().min(), Some(&3));
+    assert_eq!([first.0, second.0].iter().max(), Some(&17));
+
+    // Un explorateur en panne libère sa frontière
+    robots[0].energy = 0.0;
+    station.handle_failures(&map, &mut robots);
+    assert_eq!(robots[0].mode, RobotMode::Stranded);
+    assert!(!station.frontier_claims.contains_key(&1));
+    assert!(!station.is_frontier_claimed(first, 2, robots[1].vision_range() * 2));
+}
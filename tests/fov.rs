@@ -0,0 +1,66 @@
+// tests/fov.rs
+use ereea::fov::field_of_view;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+// Mur vertical en x = 6 (y de 2 à 8) avec un gisement caché derrière
+fn walled_map() -> Map {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    for row in tiles.iter_mut().take(9).skip(2) {
+        row[6] = TileType::Obstacle;
+    }
+    tiles[5][7] = TileType::Mineral;
+    Map::from_tiles(tiles, 0, 0, MovementRules::default())
+}
+
+#[test]
+fn vision_range_is_circular() {
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 0, 0, MovementRules::default());
+
+    // Rayon 2: le carré 5x5 sans ses coins
+    let visible = field_of_view(&map, (10, 10), 2);
+    assert_eq!(visible.len(), 21);
+    assert!(!visible.contains(&(12, 12)));
+    assert!(visible.contains(&(12, 11)));
+    // Chaque case une seule fois, ligne par ligne
+    assert!(visible.windows(2).all(|pair| (pair[0].1, pair[0].0) < (pair[1].1, pair[1].0)));
+
+    // Rayon 1: les diagonales restent visibles
+    assert_eq!(field_of_view(&map, (10, 10), 1).len(), 9);
+
+    // Bord de carte
+    assert_eq!(field_of_view(&map, (0, 0), 1).len(), 4);
+}
+
+#[test]
+fn obstacles_hide_what_is_behind_them() {
+    let map = walled_map();
+    let visible = field_of_view(&map, (4, 5), 3);
+
+    assert!(visible.contains(&(5, 5)));
+    assert!(visible.contains(&(6, 5)), "le mur lui-même est visible");
+    assert!(!visible.contains(&(7, 5)));
+    assert!(!visible.contains(&(7, 4)));
+
+    // Au-delà de l'extrémité du mur, la vue est dégagée
+    assert!(field_of_view(&map, (5, 9), 3).contains(&(7, 9)));
+}
+
+#[test]
+fn robots_only_map_what_they_can_see() {
+    let map = walled_map();
+    let mut station = Station::new();
+    let mut robot = Robot::new_with_memory(5, 5, RobotType::Explorer, 1, 0, 0, station.global_memory.clone());
+
+    robot.update_memory(&map, &mut station);
+
+    assert!(robot.memory[5][6].explored);
+    assert_eq!(robot.memory[5][6].tile, TileType::Obstacle);
+    assert!(!robot.memory[5][7].explored);
+    assert!(!robot.memory[5][8].explored);
+    assert!(robot.memory[2][5].explored);
+    assert!(!robot.memory[2][8].explored);
+}
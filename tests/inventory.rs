@@ -0,0 +1,54 @@
+// tests/inventory.rs
+use ereea::behavior::{Action, Observation};
+use ereea::inventory::{CargoKind, Inventory};
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+#[test]
+fn slots_follow_the_robot_type() {
+    let minerals = Inventory::for_robot(RobotType::MineralCollector);
+    assert_eq!(minerals.capacity(CargoKind::Mineral), 5);
+    assert_eq!(minerals.capacity(CargoKind::ScientificData), 0);
+    assert_eq!(Inventory::for_robot(RobotType::ScientificCollector).capacity(CargoKind::ScientificData), 3);
+    assert!(Inventory::for_robot(RobotType::Explorer).slots().is_empty());
+
+    let mut cells = Inventory::for_robot(RobotType::EnergyCollector);
+    assert_eq!(cells.add(CargoKind::EnergyCell, 7), 5);
+    assert!(cells.is_full());
+    assert_eq!(cells.add(CargoKind::Mineral, 1), 0);
+    assert_eq!(cells.take(CargoKind::EnergyCell, 2), 2);
+    assert_eq!(cells.load(), 0.6);
+}
+
+#[test]
+fn robots_only_collect_what_they_have_room_for() {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[5][5] = TileType::Scientific;
+    let mut map = Map::from_tiles(tiles, 2, 2, MovementRules::default());
+    let mut station = Station::new();
+
+    let mut miner = Robot::new_with_memory(5, 5, RobotType::MineralCollector, 1, 2, 2, station.global_memory.clone());
+    miner.perform(Action::Collect, &mut map, &mut station);
+    assert_eq!(miner.inventory.total_units(), 0);
+    assert_eq!(map.get_tile(5, 5), TileType::Scientific);
+
+    let mut scientist = Robot::new_with_memory(5, 5, RobotType::ScientificCollector, 2, 2, 2, station.global_memory.clone());
+    scientist.perform(Action::Collect, &mut map, &mut station);
+    assert_eq!(scientist.inventory.amount(CargoKind::ScientificData), 1);
+}
+
+#[test]
+fn cargo_mass_makes_moves_costlier_and_full_holds_go_home() {
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default());
+    let mut miner = Robot::new_with_memory(10, 10, RobotType::MineralCollector, 1, 2, 2, Station::new().global_memory);
+    let empty = miner.energy_for_travel(100);
+    assert!(!Observation::gather(&miner, &map).needs_return);
+
+    miner.inventory.add(CargoKind::Mineral, 5);
+
+    assert!(miner.energy_for_travel(100) > empty);
+    assert!(Observation::gather(&miner, &map).needs_return);
+}
@@ -0,0 +1,147 @@
+// tests/knowledge.rs
+use ereea::audit::ResolutionPolicy;
+use ereea::knowledge::{merge_tile, KnowledgeStats, PeerExchange};
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+// Robot loin de sa station (0, 0) qui connaît `known` cases de la ligne `row`
+fn robot_knowing(id: usize, x: usize, y: usize, row: usize, known: usize, timestamp: u32) -> Robot {
+    let unknown = TerrainData { explored: false, timestamp: 0, robot_id: 0, robot_type: RobotType::Explorer, tile: TileType::Empty, confidence: 0.0 };
+    let mut memory = vec![vec![unknown; MAP_SIZE]; MAP_SIZE];
+    for tile in memory[row].iter_mut().take(known) {
+        *tile = TerrainData { explored: true, timestamp, robot_id: id, robot_type: RobotType::Explorer, tile: TileType::Empty, confidence: 1.0 };
+    }
+    Robot::new_with_memory(x, y, RobotType::Explorer, id, 0, 0, memory)
+}
+
+fn known_tiles(robot: &Robot) -> usize {
+    robot.memory.iter().flatten().filter(|t| t.explored).count()
+}
+
+#[test]
+fn newer_data_wins_the_merge() {
+    let old = TerrainData { explored: true, timestamp: 3, robot_id: 1, robot_type: RobotType::Explorer, tile: TileType::Empty, confidence: 1.0 };
+    let new = TerrainData { explored: true, timestamp: 7, robot_id: 2, robot_type: RobotType::Explorer, tile: TileType::Empty, confidence: 1.0 };
+
+    let mut tile = old.clone();
+    assert!(merge_tile(&mut tile, &new));
+    assert_eq!(tile.robot_id, 2);
+    assert!(!merge_tile(&mut tile, &old));
+    assert_eq!(tile.timestamp, 7);
+}
+
+#[test]
+fn robots_out_of_range_do_not_exchange() {
+    let mut robots = vec![robot_knowing(1, 5, 5, 0, 10, 1), robot_knowing(2, 15, 15, 1, 10, 1)];
+    let mut exchange = PeerExchange::new(3, 100);
+    let mut stats = KnowledgeStats::default();
+
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+
+    assert_eq!(known_tiles(&robots[0]), 10);
+    assert_eq!(stats, KnowledgeStats::default());
+}
+
+#[test]
+fn bandwidth_is_spent_once_per_encounter() {
+    let mut robots = vec![robot_knowing(1, 5, 5, 0, 15, 1), robot_knowing(2, 6, 6, 1, 3, 1)];
+    let mut exchange = PeerExchange::new(3, 10);
+    let mut stats = KnowledgeStats::default();
+
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+    assert_eq!(known_tiles(&robots[1]), 3 + 10);
+    assert_eq!(known_tiles(&robots[0]), 15 + 3);
+
+    // Toujours à portée: même rencontre, budget épuisé
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+    assert_eq!(known_tiles(&robots[1]), 13);
+    assert_eq!(stats.peer_encounters, 1);
+
+    // Après s'être séparés, une nouvelle rencontre recharge le budget
+    robots[1].x = 15;
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+    robots[1].x = 6;
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+
+    assert_eq!(known_tiles(&robots[1]), 18);
+    assert_eq!(stats.peer_encounters, 2);
+    assert_eq!(stats.tiles_via_peers, 13 + 5);
+}
+
+#[test]
+fn lowering_the_bandwidth_during_an_encounter_stops_the_transfer() {
+    let mut robots = vec![robot_knowing(1, 5, 5, 0, 15, 1), robot_knowing(2, 6, 6, 1, 3, 1)];
+    let mut exchange = PeerExchange::new(3, 10);
+    let mut stats = KnowledgeStats::default();
+
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+    exchange.bandwidth = 4;
+    exchange.exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+
+    assert_eq!(known_tiles(&robots[1]), 3 + 10);
+    assert_eq!(stats.tiles_via_peers, 10 + 3);
+}
+
+#[test]
+fn peers_resolve_disagreements_with_the_station_policy() {
+    // Le robot 2 a revu plus tard les mêmes cases, avec moins de confiance
+    let mut doubtful = robot_knowing(2, 6, 6, 0, 5, 9);
+    for tile in doubtful.memory[0].iter_mut().take(5) {
+        tile.confidence = 0.4;
+    }
+    let mut robots = vec![robot_knowing(1, 5, 5, 0, 5, 1), doubtful];
+    let mut stats = KnowledgeStats::default();
+
+    PeerExchange::new(3, 100).exchange(&mut robots, ResolutionPolicy::MostConfident, &mut stats);
+    assert_eq!(stats.tiles_via_peers, 5);
+    assert!(robots.iter().all(|robot| robot.memory[0][..5].iter().all(|t| t.robot_id == 1)));
+
+    // La règle par défaut fait primer la fraîcheur de l'observation
+    let mut robots = vec![robot_knowing(1, 5, 5, 0, 5, 1), robot_knowing(2, 6, 6, 0, 5, 90)];
+    PeerExchange::new(3, 100).exchange(&mut robots, ResolutionPolicy::default(), &mut stats);
+    assert!(robots.iter().all(|robot| robot.memory[0][..5].iter().all(|t| t.robot_id == 2)));
+}
+
+#[test]
+fn syncs_only_carry_what_changed_since_the_last_one() {
+    let mut station = Station::new();
+    let mut robots = vec![robot_knowing(1, 0, 0, 0, 10, 1), robot_knowing(2, 1, 1, 1, 5, 2)];
+    station.share_knowledge(&mut robots[0]);
+    assert_eq!(station.knowledge_stats.tiles_via_station, 10);
+    assert!(robots[0].changes.is_empty());
+
+    // Une case apprise depuis: seule elle part, et la station n'a rien de neuf à renvoyer
+    let mut seen = robots[0].memory[0][0].clone();
+    seen.timestamp = 3;
+    robots[0].learn((4, 3), seen);
+    station.share_knowledge(&mut robots[0]);
+    assert_eq!(station.knowledge_stats.tiles_via_station, 11);
+    assert!(station.global_memory[3][4].explored);
+    assert!(station.replica.delta(&station.global_memory, &robots[0].clock).is_empty());
+
+    // Les cases reçues d'un autre robot sont transmises à la synchronisation suivante
+    PeerExchange::new(3, 100).exchange(&mut robots, ResolutionPolicy::default(), &mut KnowledgeStats::default());
+    assert_eq!(robots[0].changes.len(), 5);
+    station.share_knowledge(&mut robots[0]);
+    assert!(station.global_memory[1].iter().take(5).all(|tile| tile.explored));
+}
+
+#[test]
+fn new_robots_leave_the_yard_with_the_station_knowledge() {
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 0, 0, MovementRules::default());
+    let mut station = Station::new();
+    station.share_knowledge(&mut robot_knowing(1, 0, 0, 0, 10, 1));
+    station.collected_minerals = 100;
+
+    let mut built = None;
+    while built.is_none() {
+        built = station.advance_construction(&map, &[]);
+    }
+    let robot = built.unwrap();
+    assert_eq!(known_tiles(&robot), 10);
+    assert!(robot.changes.is_empty());
+    assert!(station.replica.delta(&station.global_memory, &robot.clock).is_empty());
+}
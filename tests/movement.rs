@@ -0,0 +1,207 @@
+// tests/movement.rs
+// Vérifie que l'A* des robots est optimal pour chaque combinaison de règles de déplacement
+use ereea::map::Map;
+use ereea::movement::{Connectivity, DistanceMetric, MovementRules, STEP_COST, DIAGONAL_STEP_COST};
+use ereea::robot::Robot;
+use ereea::scenario::Scenario;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use std::collections::{BinaryHeap, VecDeque};
+use std::cmp::Reverse;
+
+// Carte aléatoire reproductible avec une densité d'obstacles donnée
+fn random_map(seed: u64, density: f64, rules: MovementRules) -> Map {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+
+    for row in tiles.iter_mut() {
+        for tile in row.iter_mut() {
+            if rng.gen_bool(density) {
+                *tile = TileType::Obstacle;
+            }
+        }
+    }
+
+    let station = MAP_SIZE / 2;
+    tiles[station][station] = TileType::Empty;
+    Map::from_tiles(tiles, station, station, rules)
+}
+
+// Référence: plus court chemin par recherche en largeur (coûts unitaires)
+// ou par Dijkstra (coûts pondérés), sans aucune heuristique
+fn reference_cost(map: &Map, start: (usize, usize), goal: (usize, usize)) -> Option<usize> {
+    let passable = |x: usize, y: usize| map.is_valid_position(x, y);
+    let unit_cost = matches!(
+        (map.movement.metric, map.movement.connectivity),
+        (DistanceMetric::Chebyshev, Connectivity::Eight) | (_, Connectivity::Four)
+    );
+
+    if unit_cost {
+        let mut dist = vec![vec![usize::MAX; MAP_SIZE]; MAP_SIZE];
+        let mut queue = VecDeque::new();
+        dist[start.1][start.0] = 0;
+        queue.push_back(start);
+
+        while let Some(pos) = queue.pop_front() {
+            if pos == goal {
+                return Some(dist[pos.1][pos.0] * STEP_COST);
+            }
+            for next in map.movement.neighbors(pos, passable) {
+                if dist[next.1][next.0] == usize::MAX {
+                    dist[next.1][next.0] = dist[pos.1][pos.0] + 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+        return None;
+    }
+
+    let mut dist = vec![vec![usize::MAX; MAP_SIZE]; MAP_SIZE];
+    let mut heap = BinaryHeap::new();
+    dist[start.1][start.0] = 0;
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, pos))) = heap.pop() {
+        if pos == goal {
+            return Some(cost);
+        }
+        if cost > dist[pos.1][pos.0] {
+            continue;
+        }
+        for next in map.movement.neighbors(pos, passable) {
+            let next_cost = cost + map.movement.step_cost(pos, next);
+            if next_cost < dist[next.1][next.0] {
+                dist[next.1][next.0] = next_cost;
+                heap.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+// Vérifie qu'un chemin est légal et retourne son coût
+fn path_cost(map: &Map, start: (usize, usize), path: &VecDeque<(usize, usize)>) -> usize {
+    let mut current = start;
+    let mut cost = 0;
+
+    for &step in path {
+        let neighbors = map.movement.neighbors(current, |x, y| map.is_valid_position(x, y));
+        assert!(neighbors.contains(&step), "pas illégal de {:?} vers {:?}", current, step);
+        cost += map.movement.step_cost(current, step);
+        current = step;
+    }
+
+    cost
+}
+
+fn all_rules() -> Vec<MovementRules> {
+    let mut rules = Vec::new();
+    for metric in [DistanceMetric::Manhattan, DistanceMetric::Chebyshev, DistanceMetric::Octile] {
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            for corner_cutting in [true, false] {
+                rules.push(MovementRules::new(metric, connectivity, corner_cutting));
+            }
+        }
+    }
+    rules
+}
+
+#[test]
+fn astar_matches_reference_for_all_rules() {
+    for rules in all_rules() {
+        for seed in 0..20 {
+            let map = random_map(seed, 0.3, rules);
+            let mut rng = StdRng::seed_from_u64(seed + 1000);
+
+            for _ in 0..10 {
+                let start = (rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+                let goal = (rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+                if !map.is_valid_position(start.0, start.1) || !map.is_valid_position(goal.0, goal.1) {
+                    continue;
+                }
+
+                let robot = Robot::new(start.0, start.1, RobotType::Explorer);
+                let path = robot.find_path(&map, goal);
+                let expected = reference_cost(&map, start, goal);
+
+                match expected {
+                    Some(cost) if start == goal => assert_eq!(cost, 0),
+                    Some(cost) => {
+                        assert_eq!(path.back(), Some(&goal));
+                        assert_eq!(path_cost(&map, start, &path), cost, "{:?} seed {}", rules, seed);
+                    }
+                    None => assert!(path.is_empty(), "chemin trouvé vers une case inaccessible"),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn heuristic_never_overestimates() {
+    for rules in all_rules() {
+        let map = random_map(7, 0.0, rules);
+        for goal in [(0, 0), (MAP_SIZE - 1, 3), (5, MAP_SIZE - 1)] {
+            for y in 0..MAP_SIZE {
+                for x in 0..MAP_SIZE {
+                    let cost = reference_cost(&map, (x, y), goal).unwrap();
+                    assert!(rules.heuristic((x, y), goal) <= cost, "{:?} surestime depuis ({}, {})", rules, x, y);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn diagonal_moves_follow_the_metric() {
+    let a = (2, 2);
+    let b = (5, 4);
+    assert_eq!(DistanceMetric::Manhattan.distance(a, b), 5 * STEP_COST);
+    assert_eq!(DistanceMetric::Chebyshev.distance(a, b), 3 * STEP_COST);
+    assert_eq!(DistanceMetric::Octile.distance(a, b), 2 * DIAGONAL_STEP_COST + STEP_COST);
+}
+
+#[test]
+fn corner_cutting_is_forbidden_when_disabled() {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[4][5] = TileType::Obstacle;
+    let strict = MovementRules::new(DistanceMetric::Octile, Connectivity::Eight, false);
+    let relaxed = MovementRules { corner_cutting: true, ..strict };
+
+    let strict_map = Map::from_tiles(tiles.clone(), 0, 0, strict);
+    let relaxed_map = Map::from_tiles(tiles, 0, 0, relaxed);
+
+    // (4, 4) -> (5, 5) frôle l'obstacle en (5, 4)
+    assert!(!strict.neighbors((4, 4), |x, y| strict_map.is_valid_position(x, y)).contains(&(5, 5)));
+    assert!(relaxed.neighbors((4, 4), |x, y| relaxed_map.is_valid_position(x, y)).contains(&(5, 5)));
+}
+
+#[test]
+fn four_connectivity_never_moves_diagonally() {
+    let rules = MovementRules::new(DistanceMetric::Manhattan, Connectivity::Four, true);
+    let map = random_map(3, 0.0, rules);
+    let robot = Robot::new(0, 0, RobotType::Explorer);
+    let path = robot.find_path(&map, (6, 9));
+
+    assert_eq!(path.len(), 15);
+    let mut current: (usize, usize) = (0, 0);
+    for step in path {
+        assert_eq!(current.0.abs_diff(step.0) + current.1.abs_diff(step.1), 1);
+        current = step;
+    }
+}
+
+#[test]
+fn scenario_selects_the_movement_rules() {
+    let scenario = Scenario::parse(r#"{
+        "movement": { "metric": "Octile", "connectivity": "Four", "corner_cutting": false }
+    }"#).unwrap();
+    let rules = MovementRules::new(DistanceMetric::Octile, Connectivity::Four, false);
+    assert_eq!(scenario.movement, rules);
+    assert_eq!(scenario.map().movement, rules);
+
+    // Sans précision, les règles historiques s'appliquent
+    assert_eq!(Scenario::parse("{}").unwrap().map().movement, MovementRules::default());
+}
@@ -0,0 +1,164 @@
+// tests/outposts.rs
+use ereea::audit::ResolutionPolicy;
+use ereea::events::SimulationEvent;
+use ereea::inventory::CargoKind;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::network::{apply_earth_command, create_station_data, EarthCommand};
+use ereea::outposts::{OUTPOST_MINERALS, REPLICATION_INTERVAL};
+use ereea::robot::Robot;
+use ereea::scenario::Scenario;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+// Station principale en (2, 2), obstacle en (15, 15)
+fn open_map() -> Map {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[15][15] = TileType::Obstacle;
+    Map::from_tiles(tiles, 2, 2, MovementRules::default())
+}
+
+#[test]
+fn scenario_outposts_are_placed_on_reachable_ground() {
+    let mut map = open_map();
+    let scenario = Scenario::parse(r#"{ "outposts": [[15, 15]] }"#).unwrap();
+    let mut station = scenario.station();
+    scenario.place_outposts(&mut station, &mut map);
+
+    assert_eq!(map.get_tile(15, 15), TileType::Empty);
+    assert_eq!(station.outposts.sites.len(), 1);
+    assert_eq!(station.outposts.sites[0].position, (15, 15));
+    assert!(matches!(station.events.recent(1)[0].event,
+                     SimulationEvent::OutpostFounded { outpost_id: 1, founder: None, .. }));
+    assert_eq!(create_station_data(&station, 0).outposts[0].id, 1);
+}
+
+#[test]
+fn invalid_scenario_outposts_are_rejected() {
+    for outposts in ["[[20, 3]]", "[[3, 3], [3, 3]]", "[[10, 10]]", "[[3, 3], [4, 4], [5, 5]]"] {
+        assert!(Scenario::parse(&format!(r#"{{ "outposts": {} }}"#, outposts)).is_err(), "{}", outposts);
+    }
+    assert!(Scenario::parse(r#"{ "outposts": [[3, 3], [4, 4]] }"#).is_ok());
+}
+
+#[test]
+fn robots_dock_at_the_nearest_station() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.found_outpost(&mut map, (15, 15), None);
+    station.outposts.sites[0].energy_reserves = 50;
+
+    let mut robots = vec![
+        Robot::new_with_memory(15, 15, RobotType::MineralCollector, 1, 2, 2, station.global_memory.clone()),
+        Robot::new_with_memory(4, 4, RobotType::Explorer, 2, 2, 2, station.global_memory.clone()),
+    ];
+    station.update_outposts(&mut map, &mut robots);
+    assert_eq!((robots[0].home_station_x, robots[0].home_station_y), (15, 15));
+    assert_eq!((robots[1].home_station_x, robots[1].home_station_y), (2, 2));
+
+    // Le dépôt reste dans les réserves de l'avant-poste
+    let minerals = station.collected_minerals;
+    robots[0].inventory.add(CargoKind::Mineral, 3);
+    robots[0].update(&mut map, &mut station);
+    assert_eq!(station.outposts.sites[0].collected_minerals, 3);
+    assert_eq!(station.collected_minerals, minerals);
+
+    // Un avant-poste aux réserves épuisées ne peut plus recharger: ses robots rentrent à la
+    // station principale
+    station.outposts.sites[0].energy_reserves = 0;
+    robots[0].charging = false;
+    station.update_outposts(&mut map, &mut robots);
+    assert_eq!((robots[0].home_station_x, robots[0].home_station_y), (2, 2));
+}
+
+#[test]
+fn far_mineral_collectors_found_outposts_with_their_cargo() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut robots = vec![
+        Robot::new_with_memory(4, 3, RobotType::MineralCollector, 1, 2, 2, station.global_memory.clone()),
+        Robot::new_with_memory(14, 12, RobotType::MineralCollector, 2, 2, 2, station.global_memory.clone()),
+    ];
+    for robot in robots.iter_mut() {
+        robot.inventory.add(CargoKind::Mineral, OUTPOST_MINERALS);
+    }
+
+    station.update_outposts(&mut map, &mut robots);
+
+    assert_eq!(station.outposts.sites.len(), 1);
+    assert_eq!(station.outposts.sites[0].position, (14, 12));
+    assert_eq!(station.outposts.sites[0].founder, Some(2));
+    assert_eq!(robots[1].inventory.amount(CargoKind::Mineral), 0);
+    assert_eq!(robots[0].inventory.amount(CargoKind::Mineral), OUTPOST_MINERALS);
+
+    // Le fondateur, soute vide, ne s'y rattache qu'une fois ses réserves de quoi recharger; un
+    // robot qui lui rapporte des minerais peut s'y rattacher avant
+    assert_eq!((robots[1].home_station_x, robots[1].home_station_y), (2, 2));
+    let mut supplier = Robot::new_with_memory(14, 11, RobotType::MineralCollector, 3, 2, 2, station.global_memory.clone());
+    supplier.inventory.add(CargoKind::Mineral, 1);
+    robots.push(supplier);
+    station.update_outposts(&mut map, &mut robots);
+    assert_eq!((robots[1].home_station_x, robots[1].home_station_y), (2, 2));
+    assert_eq!((robots[2].home_station_x, robots[2].home_station_y), (14, 12));
+
+    station.deposit_at((14, 12), CargoKind::Mineral, station.economy.charge_rate);
+    station.update_outposts(&mut map, &mut robots);
+    assert_eq!((robots[1].home_station_x, robots[1].home_station_y), (14, 12));
+}
+
+#[test]
+fn stations_replicate_knowledge_and_forward_reserves() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.found_outpost(&mut map, (15, 15), None);
+
+    // Chaque station connaît une case que l'autre ignore
+    let policy = station.merge_policy;
+    let mut seen = station.global_memory[0][0].clone();
+    seen.explored = true;
+    station.replica.ingest(&mut station.global_memory, (0, 0), &seen, policy);
+    let outpost = &mut station.outposts.sites[0];
+    seen.tile = TileType::Mineral;
+    outpost.replica.ingest(&mut outpost.memory, (19, 19), &seen, policy);
+    outpost.collected_minerals = 4;
+    outpost.energy_reserves = 7;
+
+    let (energy, minerals) = (station.energy_reserves, station.collected_minerals);
+    station.current_time = REPLICATION_INTERVAL;
+    station.update_outposts(&mut map, &mut []);
+
+    assert_eq!(station.global_memory[19][19].tile, TileType::Mineral);
+    assert!(station.outposts.sites[0].memory[0][0].explored);
+    assert_eq!(station.knowledge_stats.tiles_via_replication, 2);
+    // L'avant-poste garde son énergie pour recharger les robots à son quai
+    assert_eq!((station.energy_reserves, station.collected_minerals), (energy, minerals + 4));
+    assert_eq!(station.outposts.sites[0].collected_minerals, 0);
+    assert_eq!(station.outposts.sites[0].energy_reserves, 7);
+}
+
+#[test]
+fn stations_still_agree_after_the_merge_policy_changes() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.set_merge_policy(ResolutionPolicy::MostConfident);
+    station.found_outpost(&mut map, (15, 15), None);
+
+    // Les deux stations retiennent une observation récente
+    let mut recent = station.global_memory[3][3].clone();
+    (recent.explored, recent.timestamp, recent.confidence) = (true, 5, 0.6);
+    station.replica.ingest(&mut station.global_memory, (3, 3), &recent, ResolutionPolicy::MostConfident);
+    station.current_time = REPLICATION_INTERVAL;
+    station.update_outposts(&mut map, &mut []);
+
+    // La station principale retient ensuite une observation plus ancienne mais plus sûre
+    let older = TerrainData { timestamp: 3, confidence: 0.9, tile: TileType::Mineral, ..recent.clone() };
+    station.replica.ingest(&mut station.global_memory, (3, 3), &older, ResolutionPolicy::MostConfident);
+
+    // Avec la nouvelle règle, l'avant-poste écarte l'observation ancienne: la station principale
+    // doit réévaluer la sienne pour retrouver le même état
+    apply_earth_command(&mut station, EarthCommand::MergePolicy(ResolutionPolicy::LatestWins));
+    station.current_time = 2 * REPLICATION_INTERVAL;
+    station.update_outposts(&mut map, &mut []);
+    assert_eq!(station.global_memory[3][3], recent);
+    assert_eq!(station.outposts.sites[0].memory[3][3], recent);
+}
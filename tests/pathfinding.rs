@@ -0,0 +1,78 @@
+// tests/pathfinding.rs
+// Vérifie la cohérence des champs de distance en cache avec l'A* ponctuel
+use ereea::map::Map;
+use ereea::movement::{Connectivity, DistanceMetric, MovementRules};
+use ereea::types::{TileType, MAP_SIZE};
+
+use std::collections::VecDeque;
+
+fn cost_of(map: &Map, start: (usize, usize), path: &VecDeque<(usize, usize)>) -> usize {
+    let mut current = start;
+    let mut cost = 0;
+    for &step in path {
+        cost += map.movement.step_cost(current, step);
+        current = step;
+    }
+    cost
+}
+
+fn walled_map(rules: MovementRules) -> Map {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    // Un mur vertical avec une seule ouverture en bas
+    for row in tiles.iter_mut().take(MAP_SIZE - 2) {
+        row[7] = TileType::Obstacle;
+    }
+    tiles[2][15] = TileType::Mineral;
+    tiles[17][3] = TileType::Mineral;
+    Map::from_tiles(tiles, 10, 10, rules)
+}
+
+#[test]
+fn goal_field_matches_astar_costs() {
+    let rules = MovementRules::new(DistanceMetric::Octile, Connectivity::Eight, false);
+    let map = walled_map(rules);
+    let station = (map.station_x, map.station_y);
+
+    for y in 0..MAP_SIZE {
+        for x in 0..MAP_SIZE {
+            if !map.is_valid_position(x, y) {
+                continue;
+            }
+            let astar = map.find_path((x, y), station);
+            let field = map.path_to_goal((x, y), station);
+            assert_eq!(cost_of(&map, (x, y), &field), cost_of(&map, (x, y), &astar));
+            assert_eq!(map.travel_cost_to_goal((x, y), station), Some(cost_of(&map, (x, y), &astar)));
+        }
+    }
+}
+
+#[test]
+fn resource_field_follows_travel_cost_not_straight_line() {
+    let map = walled_map(MovementRules::default());
+
+    // (5, 2) est plus proche à vol d'oiseau de (15, 2), mais le mur impose un détour
+    let (target, _) = map.nearest_resource((5, 2), TileType::Mineral).unwrap();
+    assert_eq!(target, (3, 17));
+
+    let path = map.path_to_nearest_resource((5, 2), TileType::Mineral).unwrap();
+    assert_eq!(path.back(), Some(&(3, 17)));
+}
+
+#[test]
+fn caches_are_invalidated_when_tiles_change() {
+    let mut map = walled_map(MovementRules::default());
+    let station = (map.station_x, map.station_y);
+    let before = map.travel_cost_to_goal((0, 0), station).unwrap();
+
+    // Percer le mur raccourcit le trajet
+    map.set_tile(7, 0, TileType::Empty);
+    let after = map.travel_cost_to_goal((0, 0), station).unwrap();
+    assert!(after < before);
+
+    // Une ressource consommée disparaît de la carte de Dijkstra
+    map.consume_resource(3, 17);
+    let (target, _) = map.nearest_resource((5, 2), TileType::Mineral).unwrap();
+    assert_eq!(target, (15, 2));
+    map.consume_resource(15, 2);
+    assert!(map.nearest_resource((5, 2), TileType::Mineral).is_none());
+}
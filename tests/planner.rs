@@ -0,0 +1,102 @@
+// tests/planner.rs
+use ereea::inventory::CargoKind;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::network::create_station_data;
+use ereea::planner::{BalancedPlanner, BuildDecision, FleetPlanner, FleetView};
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+use std::collections::HashMap;
+
+// Mémoire entièrement explorée et vide, sauf `count` gisements de `tile`
+fn knowledge(tile: TileType, count: usize) -> Vec<Vec<TerrainData>> {
+    let mut memory = Station::new().global_memory;
+    for row in memory.iter_mut() {
+        for data in row.iter_mut() {
+            data.explored = true;
+        }
+    }
+    for row in memory.iter_mut().take(count) {
+        row[1].tile = tile.clone();
+    }
+    memory
+}
+
+fn view<'a>(knowledge: &'a [Vec<TerrainData>], fleet: &[(RobotType, usize)], unexplored: f32, energy_trend: f32) -> FleetView<'a> {
+    FleetView {
+        knowledge,
+        composition: fleet.iter().copied().collect::<HashMap<_, _>>(),
+        unexplored,
+        energy_reserves: 100,
+        collected_minerals: 0,
+        energy_trend,
+        mineral_trend: 1.0,
+        out_of_contact: 0,
+        relay_count: 0,
+        needs_repairer: false,
+    }
+}
+
+#[test]
+fn planner_weighs_known_deposits_against_the_fleet() {
+    let minerals = knowledge(TileType::Mineral, 6);
+    let planner = BalancedPlanner;
+
+    // 6 gisements connus sans collecteur l'emportent sur la moitié de la carte à explorer
+    let decision = planner.plan(&view(&minerals, &[], 0.5, 1.0));
+    assert_eq!(decision.robot_type, RobotType::MineralCollector);
+    assert!(decision.rationale.contains("6 gisements connus pour 0 collecteur"));
+
+    // Déjà trois collecteurs: un explorateur devient plus utile
+    let fleet = [(RobotType::MineralCollector, 3)];
+    assert_eq!(planner.plan(&view(&minerals, &fleet, 0.5, 1.0)).robot_type, RobotType::Explorer);
+
+    // Réserves d'énergie en baisse: les gisements d'énergie comptent double
+    let energy = knowledge(TileType::Energy, 3);
+    assert_eq!(planner.plan(&view(&energy, &[], 0.5, 1.0)).robot_type, RobotType::Explorer);
+    assert_eq!(planner.plan(&view(&energy, &[], 0.5, -0.5)).robot_type, RobotType::EnergyCollector);
+}
+
+#[test]
+fn station_plans_from_its_knowledge_not_the_map() {
+    // Gisements partout sur la carte, mais la station n'en connaît aucun
+    let map = Map::from_tiles(vec![vec![TileType::Mineral; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default());
+    let mut station = Station::new();
+
+    station.advance_construction(&map, &[]);
+    assert_eq!(station.construction.head().unwrap().robot_type, RobotType::Explorer);
+    let plan = create_station_data(&station, 0).build_plan.unwrap();
+    assert!(plan.rationale.contains("100% de la carte inexplorée"));
+}
+
+#[test]
+fn planner_can_be_swapped() {
+    struct RelaysOnly;
+    impl FleetPlanner for RelaysOnly {
+        fn plan(&self, _view: &FleetView) -> BuildDecision {
+            BuildDecision { robot_type: RobotType::Relay, rationale: "test".to_string() }
+        }
+    }
+
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default());
+    let mut station = Station::new();
+    station.planner = Box::new(RelaysOnly);
+    station.advance_construction(&map, &[]);
+    assert_eq!(station.construction.head().unwrap().robot_type, RobotType::Relay);
+}
+
+#[test]
+fn resource_trend_follows_income_and_upkeep() {
+    let mut station = Station::new();
+    station.economy.upkeep_per_robot = 1.0;
+
+    station.deposit(CargoKind::Mineral, 4);
+    station.pay_upkeep(2);
+    station.tick();
+    station.pay_upkeep(2);
+    station.tick();
+
+    // (4 - 2 - 2) / 2 tours d'énergie, 4 / 2 tours de minerais
+    assert_eq!(station.trend.energy_rate(), 0.0);
+    assert_eq!(station.trend.mineral_rate(), 2.0);
+}
@@ -0,0 +1,256 @@
+// tests/rescue.rs
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::network::create_simulation_state;
+use ereea::rescue::{RescueMethod, TOW_ENERGY_FACTOR};
+use ereea::inventory::{CargoKind, ENERGY_PER_CELL};
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+
+const STATION: (usize, usize) = (2, 2);
+
+fn open_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], STATION.0, STATION.1, MovementRules::default())
+}
+
+fn robot(id: usize, x: usize, y: usize, robot_type: RobotType, station: &Station) -> Robot {
+    Robot::new_with_memory(x, y, robot_type, id, STATION.0, STATION.1, station.global_memory.clone())
+}
+
+// Un collecteur à sec loin de la station et un sauveteur à la station
+fn breakdown_with(rescuer_type: RobotType, station: &Station) -> Vec<Robot> {
+    let mut stranded = robot(1, 12, 12, RobotType::MineralCollector, station);
+    stranded.energy = 0.0;
+    stranded.inventory.add(CargoKind::Mineral, 2);
+    vec![stranded, robot(2, STATION.0, STATION.1, rescuer_type, station)]
+}
+
+fn turn(map: &mut Map, station: &mut Station, robots: &mut Vec<Robot>) {
+    station.tick();
+    station.update_traffic(robots);
+    for robot in robots.iter_mut() {
+        robot.update(map, station);
+    }
+    station.handle_failures(map, robots);
+    station.update_communications(map, robots);
+}
+
+// Joue au plus `turns` tours; retourne true dès que le robot en panne est de retour à la station
+fn run_until_home(map: &mut Map, station: &mut Station, robots: &mut Vec<Robot>, turns: usize) -> bool {
+    for _ in 0..turns {
+        turn(map, station, robots);
+        if robots[0].is_at_station() {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn energy_collector_recharges_a_stranded_robot() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut robots = breakdown_with(RobotType::EnergyCollector, &station);
+
+    turn(&mut map, &mut station, &mut robots);
+    assert_eq!(robots[0].mode, RobotMode::Stranded);
+    assert_eq!(station.rescue.rescuer_of(1), Some((2, RescueMethod::EnergyTransfer)));
+
+    // Relancé avec de quoi rentrer, le robot revient à la station par ses propres moyens
+    assert!(run_until_home(&mut map, &mut station, &mut robots, 60));
+    assert_eq!(station.rescue.stats.stranded, 1);
+    assert_eq!(station.rescue.stats.recharged, 1);
+    assert!(station.rescue.stranded().is_empty());
+    assert!(robots.iter().all(|r| r.rescue.is_none()));
+}
+
+#[test]
+fn other_robots_tow_the_stranded_robot_home() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut robots = breakdown_with(RobotType::MineralCollector, &station);
+
+    turn(&mut map, &mut station, &mut robots);
+    assert_eq!(station.rescue.rescuer_of(1), Some((2, RescueMethod::Tow)));
+
+    let mut previous = (robots[0].x, robots[0].y);
+    for _ in 0..60 {
+        turn(&mut map, &mut station, &mut robots);
+        let position = (robots[0].x, robots[0].y);
+        assert!(position == previous || robots[0].is_next_to(&map, previous), "remorque détachée");
+        assert!(position != (robots[1].x, robots[1].y) || robots[0].is_at_station());
+        previous = position;
+        if robots[0].is_at_station() {
+            break;
+        }
+    }
+
+    assert!(robots[0].is_at_station());
+    assert_eq!(station.rescue.stats.towed, 1);
+    assert_eq!(robots[0].mode, RobotMode::Idle);
+
+    // Amarré à la station, le robot remorqué commence sa recharge au tour suivant
+    let energy = robots[0].energy;
+    turn(&mut map, &mut station, &mut robots);
+    assert!(robots[0].charging);
+    assert!(robots[0].energy > energy);
+}
+
+#[test]
+fn robot_without_rescue_is_written_off() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.rescue.timeout = 20;
+    let mut robots = breakdown_with(RobotType::Explorer, &station);
+    robots.truncate(1);
+
+    for _ in 0..25 {
+        turn(&mut map, &mut station, &mut robots);
+    }
+
+    assert!(robots.is_empty());
+    assert_eq!(station.rescue.stats.written_off, 1);
+    assert_eq!(create_simulation_state(&map, &station, &robots, 25).station_data.robot_count, 0);
+    assert_eq!(station.rescue.stats.cargo_lost, 2);
+    let events: Vec<String> = station.events.recent(10).iter().map(|r| r.event.to_string()).collect();
+    assert!(events.iter().any(|e| e.contains("abandonné")));
+}
+
+#[test]
+fn harvested_energy_is_carried_back_to_the_station() {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[3][3] = TileType::Energy;
+    let mut map = Map::from_tiles(tiles, STATION.0, STATION.1, MovementRules::default());
+    let mut station = Station::new();
+    let mut collector = robot(1, 3, 3, RobotType::EnergyCollector, &station);
+
+    collector.perform(ereea::behavior::Action::Collect, &mut map, &mut station);
+    assert_eq!(collector.inventory.amount(CargoKind::EnergyCell), 1);
+    assert_eq!(collector.energy, collector.max_energy);
+
+    let reserves = station.energy_reserves;
+    collector.x = STATION.0;
+    collector.y = STATION.1;
+    collector.update(&mut map, &mut station);
+
+    assert_eq!(collector.inventory.amount(CargoKind::EnergyCell), 0);
+    assert_eq!(station.energy_reserves, reserves + ENERGY_PER_CELL);
+}
+
+#[test]
+fn energy_collector_charges_a_robot_on_request() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut explorer = robot(1, 7, 7, RobotType::Explorer, &station);
+    let needed = explorer.energy_to_return(&map).unwrap();
+    explorer.energy = needed + explorer.safety_margin(needed);
+    explorer.in_contact = true;
+    let mut charger = robot(2, 3, 3, RobotType::EnergyCollector, &station);
+    charger.inventory.add(CargoKind::EnergyCell, 3);
+    let mut robots = vec![explorer, charger];
+
+    // À portée radio de la station, l'explorateur à court d'énergie demande une recharge
+    turn(&mut map, &mut station, &mut robots);
+    assert!(robots[0].charge_requested);
+    assert_eq!(station.rescue.charger_of(1), Some(2));
+
+    for _ in 0..10 {
+        turn(&mut map, &mut station, &mut robots);
+        if station.rescue.stats.field_charges > 0 {
+            break;
+        }
+    }
+
+    assert_eq!(station.rescue.stats.field_charges, 1);
+    assert_eq!(robots[1].inventory.amount(CargoKind::EnergyCell), 0);
+    assert!(!robots[0].is_at_station());
+    assert!(robots[0].energy > needed + 3.0 * ENERGY_PER_CELL as f32 - 5.0);
+    assert!(robots.iter().all(|r| r.rescue.is_none()));
+
+    // Rechargé, l'explorateur repart au lieu de rentrer
+    turn(&mut map, &mut station, &mut robots);
+    assert_ne!(robots[0].mode, RobotMode::ReturnToStation);
+}
+
+#[test]
+fn chargers_keep_enough_energy_to_get_home() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut explorer = robot(1, 7, 7, RobotType::Explorer, &station);
+    let needed = explorer.energy_to_return(&map).unwrap();
+    explorer.energy = needed + explorer.safety_margin(needed);
+    explorer.in_contact = true;
+    // Une seule cellule et tout juste de quoi rentrer depuis le demandeur
+    let mut charger = robot(2, 7, 6, RobotType::EnergyCollector, &station);
+    charger.inventory.add(CargoKind::EnergyCell, 1);
+    let back = charger.energy_to_return(&map).unwrap();
+    charger.energy = back + charger.safety_margin(back) + 1.0;
+    let mut robots = vec![explorer, charger];
+
+    for _ in 0..10 {
+        turn(&mut map, &mut station, &mut robots);
+        if station.rescue.stats.field_charges > 0 {
+            break;
+        }
+    }
+
+    // Le collecteur ne cède que sa cellule et le surplus de sa batterie
+    assert_eq!(station.rescue.stats.field_charges, 1);
+    assert!(station.rescue.stats.energy_delivered < ENERGY_PER_CELL as f32 + 2.0);
+    assert_ne!(robots[1].mode, RobotMode::Stranded);
+    let back = robots[1].energy_to_return(&map).unwrap();
+    assert!(robots[1].energy >= back + robots[1].safety_margin(back));
+}
+
+#[test]
+fn transfers_below_a_cell_keep_the_surplus() {
+    let station = Station::new();
+    let mut charger = robot(1, 5, 5, RobotType::EnergyCollector, &station);
+    charger.inventory.add(CargoKind::EnergyCell, 2);
+    charger.energy = 50.0;
+    let mut explorer = robot(2, 5, 6, RobotType::Explorer, &station);
+    explorer.energy = explorer.max_energy - 3.0;
+    let total = |a: &Robot, b: &Robot| a.energy + b.energy
+        + (a.inventory.amount(CargoKind::EnergyCell) * ENERGY_PER_CELL) as f32;
+    let before = total(&charger, &explorer);
+
+    // Moins de place qu'une cellule: la cellule entamée recharge le donneur
+    assert_eq!(charger.transfer_energy(&mut explorer, 100.0, ENERGY_PER_CELL), 3.0);
+    assert_eq!(explorer.energy, explorer.max_energy);
+    assert_eq!(charger.inventory.amount(CargoKind::EnergyCell), 1);
+    assert_eq!(total(&charger, &explorer), before);
+
+    // Des cellules entières tant qu'elles tiennent, la batterie pour le reste
+    explorer.energy -= ENERGY_PER_CELL as f32 + 2.0;
+    charger.inventory.add(CargoKind::EnergyCell, 1);
+    let before = total(&charger, &explorer);
+    assert_eq!(charger.transfer_energy(&mut explorer, 100.0, ENERGY_PER_CELL), ENERGY_PER_CELL as f32 + 2.0);
+    assert_eq!(total(&charger, &explorer), before);
+}
+
+#[test]
+fn tows_are_only_taken_if_the_rescuer_can_reach_its_own_station() {
+    let map = open_map();
+    let mut station = Station::new();
+    let mut stranded = robot(1, 5, 5, RobotType::MineralCollector, &station);
+    stranded.energy = 0.0;
+
+    // Sauveteur rattaché à une station lointaine: de quoi remorquer jusqu'à la station du robot
+    // en panne, pas jusqu'à la sienne
+    let mut rescuer = robot(2, 6, 6, RobotType::MineralCollector, &station);
+    (rescuer.home_station_x, rescuer.home_station_y) = (19, 19);
+    let reach = rescuer.energy_for_travel(map.travel_cost_to_goal((6, 6), (5, 5)).unwrap());
+    let towing = rescuer.energy_for_travel(map.travel_cost_to_goal((5, 5), STATION).unwrap()) * (1.0 + TOW_ENERGY_FACTOR);
+    rescuer.energy = reach + towing + rescuer.safety_margin(reach + towing) + 1.0;
+    let mut robots = vec![stranded, rescuer];
+
+    station.handle_failures(&map, &mut robots);
+    assert_eq!(robots[0].mode, RobotMode::Stranded);
+    assert_eq!(station.rescue.rescuer_of(1), None);
+
+    // Basé à la même station, il peut prendre le remorquage
+    (robots[1].home_station_x, robots[1].home_station_y) = STATION;
+    station.handle_failures(&map, &mut robots);
+    assert_eq!(station.rescue.rescuer_of(1), Some((2, RescueMethod::Tow)));
+}
@@ -0,0 +1,85 @@
+// tests/research.rs
+use ereea::behavior::Action;
+use ereea::events::SimulationEvent;
+use ereea::inventory::CargoKind;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::network::{apply_earth_command, create_station_data, EarthCommand};
+use ereea::research::{Research, Technology, Upgrades, MAX_LEVEL};
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+fn empty_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 2, 2, MovementRules::default())
+}
+
+#[test]
+fn research_is_funded_level_by_level() {
+    let mut research = Research::default();
+
+    // Sans choix de la Terre, la technologie la moins avancée (optique d'abord)
+    let mut data = 3;
+    assert_eq!(research.fund(&mut data), None);
+    assert_eq!((research.current, data), (Some(Technology::Optics), 0));
+
+    // Les données investies restent acquises après un changement de projet
+    assert!(research.select(Technology::Drilling));
+    let mut data = 10;
+    assert_eq!(research.fund(&mut data), Some(Technology::Drilling));
+    assert_eq!(data, 6);
+    assert_eq!(research.fund(&mut data), Some(Technology::Optics));
+    assert_eq!((data, research.upgrades.optics, research.upgrades.drilling), (5, 1, 1));
+
+    // Coût croissant, niveau plafonné
+    assert_eq!(research.cost(Technology::Optics), Some(8));
+    research.upgrades.batteries = MAX_LEVEL;
+    assert!(!research.select(Technology::Batteries));
+    assert!(research.summary().contains("batteries 3/3"));
+}
+
+#[test]
+fn completed_research_upgrades_existing_and_new_robots() {
+    let map = empty_map();
+    let mut station = Station::new();
+    let mut robots = vec![Robot::new_with_memory(2, 2, RobotType::Explorer, 1, 2, 2, station.global_memory.clone())];
+    let base_range = robots[0].vision_range();
+
+    // La Terre choisit les batteries; les données scientifiques financent le premier niveau
+    apply_earth_command(&mut station, EarthCommand::Research(Technology::Batteries));
+    station.collected_scientific_data = 5;
+    station.update_research(&mut robots);
+
+    assert_eq!(station.collected_scientific_data, 1);
+    assert!((robots[0].max_energy - 96.0).abs() < 1e-3);
+    assert_eq!(robots[0].vision_range(), base_range);
+    assert!(matches!(station.events.recent(1)[0].event,
+                     SimulationEvent::ResearchCompleted { technology: Technology::Batteries, level: 1 }));
+    assert_eq!(create_station_data(&station, 0).research.upgrades.batteries, 1);
+
+    // Un robot construit ensuite est équipé d'office
+    station.energy_reserves = 1000;
+    station.collected_minerals = 1000;
+    let built = (0..100).find_map(|_| station.advance_construction(&map, &robots)).unwrap();
+    assert_eq!(built.upgrades, station.research.upgrades);
+    assert!(built.max_energy > ereea::robot::rated_capacity(built.robot_type));
+}
+
+#[test]
+fn upgrades_extend_vision_travel_and_mining() {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[5][5] = TileType::Mineral;
+    let mut map = Map::from_tiles(tiles, 2, 2, MovementRules::default());
+    let mut station = Station::new();
+
+    let mut miner = Robot::new_with_memory(5, 5, RobotType::MineralCollector, 1, 2, 2, station.global_memory.clone());
+    let (range, travel) = (miner.vision_range(), miner.energy_for_travel(100));
+    miner.apply_upgrades(Upgrades { optics: 1, propulsion: 2, drilling: 2, ..Upgrades::default() });
+
+    assert_eq!(miner.vision_range(), range + 1);
+    assert!(miner.energy_for_travel(100) < travel);
+
+    miner.perform(Action::Collect, &mut map, &mut station);
+    assert_eq!(miner.inventory.amount(CargoKind::Mineral), 3);
+    assert_eq!(map.get_tile(5, 5), TileType::Empty);
+}
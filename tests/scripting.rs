@@ -0,0 +1,139 @@
+// tests/scripting.rs
+use ereea::events::SimulationEvent;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::scripting::{ScriptBehavior, ScriptProgram, DEFAULT_INSTRUCTION_BUDGET};
+use ereea::behavior::default_behavior;
+use ereea::station::Station;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+fn setup(source: &str) -> (Map, Station, Robot) {
+    let program = ScriptProgram::compile("test.rhai", source, DEFAULT_INSTRUCTION_BUDGET).unwrap();
+    setup_program(program, RobotType::Explorer)
+}
+
+fn setup_program(program: ScriptProgram, robot_type: RobotType) -> (Map, Station, Robot) {
+    let map = Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], 10, 10, MovementRules::default());
+    let mut robot = Robot::new(10, 10, robot_type);
+    robot.id = 1;
+    robot.set_behavior(Box::new(ScriptBehavior::new(program, default_behavior(robot_type))));
+    (map, Station::new(), robot)
+}
+
+fn script_errors(station: &Station) -> usize {
+    station.events.recent(usize::MAX).iter()
+        .filter(|r| matches!(r.event, SimulationEvent::ScriptError { .. }))
+        .count()
+}
+
+#[test]
+fn script_moves_the_robot() {
+    let (mut map, mut station, mut robot) = setup("fn decide(robot) { move_to(robot.x + 1, robot.y) }");
+
+    for _ in 0..3 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+    }
+
+    assert_eq!((robot.x, robot.y), (13, 10));
+    assert_eq!(script_errors(&station), 0);
+}
+
+#[test]
+fn script_sees_a_read_only_copy_of_the_robot() {
+    let (mut map, mut station, mut robot) = setup(
+        "fn decide(robot) { robot.energy = 0.0; robot.x = 0; if robot.memory[10][10] { idle() } else { explore() } }");
+
+    station.tick();
+    robot.update(&mut map, &mut station);
+    station.tick();
+    robot.update(&mut map, &mut station);
+
+    assert_eq!((robot.x, robot.y), (10, 10));
+    assert!(robot.energy > 0.0);
+    assert_eq!(script_errors(&station), 0);
+}
+
+#[test]
+fn runaway_script_is_stopped_and_reported() {
+    let (mut map, mut station, mut robot) = setup("fn decide(robot) { loop { } }");
+
+    for _ in 0..2 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+    }
+
+    // Le budget d'instructions arrête le script; le robot suit son comportement par défaut et
+    // l'erreur n'est journalisée qu'une fois
+    assert_eq!(script_errors(&station), 1);
+    assert_ne!((robot.x, robot.y), (10, 10));
+}
+
+#[test]
+fn repeated_errors_are_reported_once_per_script() {
+    let program = ScriptProgram::compile("test.rhai", "fn decide(robot) { \"dance\" }", DEFAULT_INSTRUCTION_BUDGET).unwrap();
+    let (mut map, mut station, mut robot) = setup_program(program.clone(), RobotType::Explorer);
+    let mut other = Robot::new(10, 10, RobotType::Explorer);
+    other.id = 2;
+    other.set_behavior(Box::new(ScriptBehavior::new(program, default_behavior(RobotType::Explorer))));
+
+    for _ in 0..5 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+        other.update(&mut map, &mut station);
+    }
+
+    assert_eq!(script_errors(&station), 1);
+}
+
+#[test]
+fn invalid_actions_are_reported() {
+    let (mut map, mut station, mut robot) = setup("fn decide(robot) { if robot.at_station { \"dance\" } else { 42 } }");
+
+    station.tick();
+    robot.update(&mut map, &mut station);
+    assert_eq!(script_errors(&station), 1);
+
+    // Loin de la station, le script échoue autrement: nouvelle erreur
+    (robot.x, robot.y) = (14, 10);
+    station.tick();
+    robot.update(&mut map, &mut station);
+    assert_eq!(script_errors(&station), 2);
+}
+
+#[test]
+fn scripts_without_decide_are_rejected() {
+    assert!(ScriptProgram::compile("bad.rhai", "fn other() { idle() }", DEFAULT_INSTRUCTION_BUDGET).is_err());
+    assert!(ScriptProgram::compile("bad.rhai", "fn decide(robot) { ", DEFAULT_INSTRUCTION_BUDGET).is_err());
+}
+
+#[test]
+fn negative_move_targets_are_reported() {
+    let (mut map, mut station, mut robot) = setup("fn decide(robot) { move_to(-3, 5) }");
+
+    station.tick();
+    robot.update(&mut map, &mut station);
+
+    assert_eq!(script_errors(&station), 1);
+    assert_ne!(robot.x, 0);
+}
+
+#[test]
+fn example_script_brings_minerals_home() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("scripts/mineral_collector.rhai");
+    let program = ScriptProgram::from_file(&path, DEFAULT_INSTRUCTION_BUDGET).unwrap();
+    assert_eq!(program.name(), "mineral_collector.rhai");
+
+    let (mut map, mut station, mut robot) = setup_program(program, RobotType::MineralCollector);
+    map.set_tile(12, 10, TileType::Mineral);
+    for _ in 0..10 {
+        station.tick();
+        robot.update(&mut map, &mut station);
+    }
+
+    // Collecte, retour et dépôt à la station
+    assert!(station.collected_minerals > 0);
+    assert_eq!((robot.x, robot.y), (10, 10));
+    assert_eq!(script_errors(&station), 0);
+}
@@ -0,0 +1,61 @@
+// tests/sensors.rs
+use ereea::knowledge::merge_tile;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::sensors::SensorModel;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+// Mur vertical en x = 6 (y de 3 à 7) avec un gisement caché derrière
+fn walled_map() -> Map {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    for row in tiles.iter_mut().take(8).skip(3) {
+        row[6] = TileType::Obstacle;
+    }
+    tiles[5][7] = TileType::Mineral;
+    Map::from_tiles(tiles, 0, 0, MovementRules::default())
+}
+
+fn observed(timestamp: u32, tile: TileType, confidence: f32) -> TerrainData {
+    TerrainData { explored: true, timestamp, robot_id: 1, robot_type: RobotType::Explorer, tile, confidence }
+}
+
+#[test]
+fn repeated_observations_build_confidence() {
+    let map = walled_map();
+    let mut station = Station::new();
+    station.sensors = Some(SensorModel { error_per_tile: 0.1 });
+    let mut robot = Robot::new_with_memory(7, 8, RobotType::Explorer, 1, 0, 0, station.global_memory.clone());
+
+    // Case à 3 de distance: 30% d'erreurs par observation
+    let mut confidences = Vec::new();
+    for _ in 0..30 {
+        station.tick();
+        robot.update_memory(&map, &mut station);
+        confidences.push(robot.memory[5][7].confidence);
+    }
+
+    assert!(confidences[0] <= 0.7 + f32::EPSILON);
+    assert_eq!(robot.memory[5][7].tile, TileType::Mineral);
+    assert!(robot.memory[5][7].confidence > 0.9);
+    // La case du robot est toujours relevée sans erreur
+    assert_eq!(robot.memory[8][7].confidence, 1.0);
+}
+
+#[test]
+fn merge_weighs_confidence_against_recency() {
+    let mut tile = observed(10, TileType::Mineral, 0.95);
+
+    // Une observation à peine plus récente mais peu sûre ne remplace pas une observation sûre
+    assert!(!merge_tile(&mut tile, &observed(12, TileType::Empty, 0.55)));
+    assert_eq!(tile.tile, TileType::Mineral);
+
+    // Une observation bien plus récente l'emporte
+    assert!(merge_tile(&mut tile, &observed(60, TileType::Empty, 0.55)));
+    assert_eq!(tile.tile, TileType::Empty);
+
+    // Même date: la plus sûre l'emporte
+    assert!(merge_tile(&mut tile, &observed(60, TileType::Empty, 0.9)));
+    assert!(!merge_tile(&mut tile, &observed(60, TileType::Mineral, 0.6)));
+}
@@ -0,0 +1,104 @@
+// tests/tasks.rs
+use ereea::inventory::CargoKind;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::Robot;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+
+const DEPOSIT: (usize, usize) = (10, 10);
+
+fn map_with_deposit() -> Map {
+    let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    tiles[DEPOSIT.1][DEPOSIT.0] = TileType::Mineral;
+    Map::from_tiles(tiles, 2, 2, MovementRules::default())
+}
+
+// Station qui connaît le gisement
+fn station_knowing_deposit() -> Station {
+    let mut station = Station::new();
+    station.global_memory[DEPOSIT.1][DEPOSIT.0] = TerrainData {
+        explored: true,
+        timestamp: 1,
+        robot_id: 1,
+        robot_type: RobotType::Explorer,
+        tile: TileType::Mineral,
+        confidence: 1.0,
+    };
+    station.current_time = 1;
+    station
+}
+
+fn collector(id: usize, x: usize, y: usize, station: &Station) -> Robot {
+    Robot::new_with_memory(x, y, RobotType::MineralCollector, id, 2, 2, station.global_memory.clone())
+}
+
+#[test]
+fn nearest_collector_wins_the_auction() {
+    let map = map_with_deposit();
+    let mut station = station_knowing_deposit();
+    let mut robots = vec![collector(1, 3, 3, &station), collector(2, 12, 12, &station)];
+
+    station.allocate_tasks(&map, &mut robots);
+
+    assert_eq!(robots[0].task, None);
+    assert_eq!(robots[1].task, Some(DEPOSIT));
+    assert_eq!(station.market.stats.awarded, 1);
+}
+
+#[test]
+fn tired_or_full_collectors_do_not_bid() {
+    let map = map_with_deposit();
+    let mut station = station_knowing_deposit();
+    let mut robots = vec![collector(1, 12, 12, &station), collector(2, 3, 3, &station)];
+    robots[0].inventory.add(CargoKind::Mineral, 5);
+
+    station.allocate_tasks(&map, &mut robots);
+    assert_eq!(robots[1].task, Some(DEPOSIT));
+
+    // Le titulaire tombe à court d'énergie: le contrat est remis aux enchères
+    robots[1].energy = 1.0;
+    robots[0].inventory.take(CargoKind::Mineral, 5);
+    station.allocate_tasks(&map, &mut robots);
+
+    assert_eq!(robots[0].task, Some(DEPOSIT));
+    assert_eq!(robots[1].task, None);
+    assert_eq!(station.market.stats.reassigned, 1);
+}
+
+#[test]
+fn contract_of_a_lost_robot_is_reassigned() {
+    let map = map_with_deposit();
+    let mut station = station_knowing_deposit();
+    let mut robots = vec![collector(1, 3, 3, &station), collector(2, 12, 12, &station)];
+    station.allocate_tasks(&map, &mut robots);
+
+    robots.remove(1);
+    station.allocate_tasks(&map, &mut robots);
+
+    assert_eq!(robots[0].task, Some(DEPOSIT));
+    assert_eq!(station.market.stats.reassigned, 1);
+}
+
+#[test]
+fn exhausted_deposit_closes_the_task() {
+    let mut map = map_with_deposit();
+    let mut station = station_knowing_deposit();
+    let mut robots = vec![collector(1, 11, 11, &station)];
+    station.allocate_tasks(&map, &mut robots);
+    assert_eq!(robots[0].task, Some(DEPOSIT));
+
+    // Le gisement est épuisé et le titulaire le voit
+    map.consume_resource(DEPOSIT.0, DEPOSIT.1);
+    station.tick();
+    robots[0].update_memory(&map, &mut station);
+    station.allocate_tasks(&map, &mut robots);
+
+    assert_eq!(robots[0].task, None);
+    assert!(station.market.tasks().is_empty());
+    assert_eq!(station.market.stats.completed, 1);
+
+    // La station, pas encore informée, ne republie pas le gisement
+    station.allocate_tasks(&map, &mut robots);
+    assert_eq!(station.market.stats.published, 1);
+}
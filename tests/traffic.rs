@@ -0,0 +1,88 @@
+// tests/traffic.rs
+use ereea::behavior::Action;
+use ereea::map::Map;
+use ereea::movement::{Connectivity, DistanceMetric, MovementRules};
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::traffic::TrafficControl;
+use ereea::types::{RobotType, TileType, MAP_SIZE};
+use std::collections::VecDeque;
+
+// Couloir horizontal d'une case de large en y = 10, avec une niche en (9, 11)
+fn corridor_map(movement: MovementRules) -> Map {
+    let mut tiles = vec![vec![TileType::Obstacle; MAP_SIZE]; MAP_SIZE];
+    for tile in tiles[10].iter_mut().take(18).skip(2) {
+        *tile = TileType::Empty;
+    }
+    tiles[11][9] = TileType::Empty;
+    Map::from_tiles(tiles, 2, 10, movement)
+}
+
+fn robot_at(id: usize, x: usize, station: &Station) -> Robot {
+    Robot::new_with_memory(x, 10, RobotType::MineralCollector, id, 2, 10, station.global_memory.clone())
+}
+
+fn cross_corridor(movement: MovementRules) {
+    let mut map = corridor_map(movement);
+    let mut station = Station::new();
+    let mut robots = vec![robot_at(1, 4, &station), robot_at(2, 14, &station)];
+    let targets = [(15, 10), (3, 10)];
+
+    for _ in 0..60 {
+        station.tick();
+        station.update_traffic(&robots);
+        for (robot, &target) in robots.iter_mut().zip(targets.iter()) {
+            if (robot.x, robot.y) != target {
+                robot.perform(Action::MoveTo(target), &mut map, &mut station);
+            }
+        }
+        assert_ne!((robots[0].x, robots[0].y), (robots[1].x, robots[1].y), "deux robots sur la même case");
+    }
+
+    assert_eq!((robots[0].x, robots[0].y), targets[0]);
+    assert_eq!((robots[1].x, robots[1].y), targets[1]);
+    assert!(station.traffic.stats.blocked_moves > 0);
+}
+
+#[test]
+fn robots_cross_in_a_corridor_using_the_side_pocket() {
+    cross_corridor(MovementRules::default());
+}
+
+#[test]
+fn robots_cross_in_a_corridor_with_four_connectivity() {
+    cross_corridor(MovementRules::new(DistanceMetric::Manhattan, Connectivity::Four, false));
+}
+
+#[test]
+fn reservations_give_way_to_lower_ids() {
+    let map = corridor_map(MovementRules::default());
+    let station = Station::new();
+    let mut traffic = TrafficControl::new();
+    traffic.sync(&[robot_at(1, 5, &station), robot_at(2, 8, &station)], 0);
+
+    // Le robot 1 réserve (6, 10) pour le tour 1
+    traffic.reserve(1, &VecDeque::from([(6, 10), (7, 10)]), 0);
+
+    assert_eq!(traffic.blocker(2, (6, 10), 1), Some(1));
+    assert_eq!(traffic.blocker(1, (8, 10), 1), Some(2));
+    assert_eq!(traffic.blocker(2, (2, 10), 1), None); // Station: pas d'exclusivité
+    assert!(traffic.retreat(&map, 2, (8, 10)).is_some());
+}
+
+#[test]
+fn side_refuge_is_off_the_other_robots_way() {
+    let map = corridor_map(MovementRules::default());
+    let station = Station::new();
+    let mut traffic = TrafficControl::new();
+
+    // Face à face de part et d'autre de la niche: chacun peut s'y écarter
+    traffic.sync(&[robot_at(1, 8, &station), robot_at(2, 9, &station)], 0);
+    assert_eq!(traffic.side_refuge(&map, 1, (8, 10), 2, (9, 10)), Some((9, 11)));
+    assert_eq!(traffic.side_refuge(&map, 2, (9, 10), 1, (8, 10)), Some((9, 11)));
+
+    // Loin de la niche: reculer n'est pas s'écarter
+    traffic.sync(&[robot_at(1, 11, &station), robot_at(2, 12, &station)], 0);
+    assert_eq!(traffic.side_refuge(&map, 2, (12, 10), 1, (11, 10)), None);
+    assert_eq!(traffic.retreat(&map, 2, (12, 10)), Some((13, 10)));
+}
@@ -0,0 +1,136 @@
+// tests/wear.rs
+use ereea::behavior::Action;
+use ereea::map::Map;
+use ereea::movement::MovementRules;
+use ereea::robot::{rated_capacity, Robot};
+use ereea::station::Station;
+use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+use ereea::wear::{Component, Health, BATTERY_REPLACEMENT_COST, DEFAULT_SEED};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const STATION: (usize, usize) = (2, 2);
+
+fn open_map() -> Map {
+    Map::from_tiles(vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE], STATION.0, STATION.1, MovementRules::default())
+}
+
+fn robot(id: usize, x: usize, y: usize, robot_type: RobotType, station: &Station) -> Robot {
+    Robot::new_with_memory(x, y, robot_type, id, STATION.0, STATION.1, station.global_memory.clone())
+}
+
+fn turn(map: &mut Map, station: &mut Station, robots: &mut Vec<Robot>) {
+    station.tick();
+    station.update_traffic(robots);
+    for robot in robots.iter_mut() {
+        robot.update(map, station);
+    }
+    station.handle_failures(map, robots);
+}
+
+// Défaillances tirées sur `ticks` tours par un composant à moitié usé
+fn failures(seed: u64, ticks: usize) -> Vec<(usize, Component)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut health = Health { motors: 0.5, sensors: 0.5, battery: 0.5, charge_cycles: 0 };
+    (0..ticks).filter_map(|t| health.wear_tick(&mut rng).map(|c| (t, c))).collect()
+}
+
+#[test]
+fn failures_depend_on_wear_and_the_seed_only() {
+    // Un robot neuf ne tombe pas en panne
+    let mut rng = StdRng::seed_from_u64(DEFAULT_SEED);
+    let mut health = Health::new();
+    assert!((0..100).all(|_| health.wear_tick(&mut rng).is_none()));
+
+    let run = failures(DEFAULT_SEED, 2000);
+    assert!(!run.is_empty());
+    assert_eq!(run, failures(DEFAULT_SEED, 2000));
+}
+
+#[test]
+fn worn_components_degrade_vision_speed_and_capacity() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut worn = robot(1, 5, 5, RobotType::Explorer, &station);
+    let fresh = robot(2, 5, 5, RobotType::Explorer, &station);
+
+    worn.health.sensors = 0.3;
+    assert!(worn.vision_range() < fresh.vision_range());
+
+    // Moteurs usés: le trajet prend plus de tours et coûte plus de métabolisme
+    worn.health.motors = 0.2;
+    assert!(worn.energy_for_travel(100) > fresh.energy_for_travel(100));
+    let mut turns = 0;
+    while (worn.x, worn.y) != (10, 5) && turns < 40 {
+        station.tick();
+        worn.perform(Action::MoveTo((10, 5)), &mut map, &mut station);
+        turns += 1;
+    }
+    assert_eq!((worn.x, worn.y), (10, 5));
+    assert!(turns >= 15, "5 cases en {} tours seulement", turns);
+
+    // Chaque recharge profonde use la batterie (la recharge au quai prend plusieurs tours)
+    let mut cycled = robot(3, STATION.0, STATION.1, RobotType::Explorer, &station);
+    station.energy_reserves = 1000;
+    for _ in 0..3 {
+        (cycled.x, cycled.y) = STATION;
+        cycled.energy = 10.0;
+        cycled.update(&mut map, &mut station);
+        while cycled.charging {
+            cycled.update(&mut map, &mut station);
+        }
+    }
+    assert_eq!(cycled.health.charge_cycles, 3);
+    assert!(cycled.max_energy < rated_capacity(RobotType::Explorer));
+}
+
+#[test]
+fn station_services_robots_at_dock() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    station.collected_minerals = BATTERY_REPLACEMENT_COST;
+    let mut docked = robot(1, STATION.0, STATION.1, RobotType::MineralCollector, &station);
+    docked.health = Health { motors: 0.4, sensors: 0.5, battery: 0.5, charge_cycles: 50 };
+
+    for _ in 0..3 {
+        (docked.x, docked.y) = STATION;
+        docked.update(&mut map, &mut station);
+    }
+
+    assert!(docked.health.is_repaired());
+    assert_eq!(docked.health.battery, 1.0);
+    assert_eq!(docked.max_energy, rated_capacity(RobotType::MineralCollector));
+    assert_eq!(station.collected_minerals, 0);
+    assert_eq!(station.maintenance.stats.batteries_replaced, 1);
+    assert_eq!(station.maintenance.stats.station_repairs, 1);
+}
+
+#[test]
+fn repair_robot_fixes_worn_robots_in_the_field() {
+    let mut map = open_map();
+    let mut station = Station::new();
+    let mut worn = robot(1, 9, 9, RobotType::Explorer, &station);
+    worn.health.motors = 0.3;
+    worn.mode = RobotMode::Idle;
+    let mut robots = vec![worn];
+
+    // Sans réparateur en service, la station en construit un
+    turn(&mut map, &mut station, &mut robots);
+    assert_eq!(station.maintenance.requests(), vec![1]);
+    assert!(station.maintenance.needs_repairer());
+
+    robots.push(robot(2, STATION.0, STATION.1, RobotType::Repair, &station));
+    for _ in 0..80 {
+        turn(&mut map, &mut station, &mut robots);
+        if station.maintenance.stats.field_repairs > 0 {
+            break;
+        }
+    }
+
+    assert_eq!(station.maintenance.stats.field_repairs, 1);
+    assert!(robots[0].health.is_repaired());
+    assert!(!robots[0].under_repair);
+    assert!(station.maintenance.requests().is_empty());
+    let events: Vec<String> = station.events.recent(10).iter().map(|r| r.event.to_string()).collect();
+    assert!(events.iter().any(|e| e.contains("remis en état par le robot #2")));
+}